@@ -76,7 +76,7 @@ def main():
 
     try:
         rust_demo.safe_divide(10, 0)
-    except ValueError as e:
+    except ZeroDivisionError as e:
         print(f"safe_divide(10, 0) raised: {e}")
 
     # -------------------------------------------------------------------------
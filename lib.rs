@@ -1,8 +1,26 @@
-use pyo3::exceptions::PyValueError;
+use log::debug;
+use numpy::ndarray::Array2;
+use numpy::{
+    IntoPyArray, PyArray1, PyArray2, PyReadonlyArray1, PyReadonlyArray2, PyReadwriteArray1,
+};
+use pyo3::create_exception;
+use pyo3::exceptions::{
+    PyIndexError, PyOverflowError, PyRuntimeError, PyValueError, PyZeroDivisionError,
+};
 use pyo3::prelude::*;
+use pyo3::types::PyTuple;
+use rand::Rng;
 use rayon::prelude::*;
 use sha2::{Digest, Sha256};
-use std::collections::HashMap;
+use std::cmp::Reverse;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{BTreeMap, BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::{Hash, Hasher};
+use std::io::{Read, Write};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::OnceLock;
+use std::time::Instant;
+use unicode_segmentation::UnicodeSegmentation;
 
 // ============================================================================
 // EXAMPLE 1: Simple Functions
@@ -31,29 +49,145 @@ fn fibonacci(n: u64) -> u64 {
 /// Count unique words in a string (case-insensitive)
 #[pyfunction]
 fn count_unique_words(text: &str) -> usize {
-    text.split_whitespace()
-        .map(|w| w.to_lowercase())
-        .collect::<std::collections::HashSet<_>>()
+    tokenize(text, true, false)
+        .into_iter()
+        .collect::<HashSet<_>>()
         .len()
 }
 
 /// Check if a string is a palindrome (ignoring spaces and case)
 #[pyfunction]
 fn is_palindrome(s: &str) -> bool {
-    let cleaned: String = s
-        .chars()
+    let cleaned = clean_alphanumeric(s);
+    let reversed: String = cleaned.chars().rev().collect();
+    cleaned == reversed
+}
+
+/// Keep only alphanumeric characters, lowercased (ASCII)
+fn clean_alphanumeric(s: &str) -> String {
+    s.chars()
         .filter(|c| c.is_alphanumeric())
         .map(|c| c.to_ascii_lowercase())
-        .collect();
+        .collect()
+}
 
-    let reversed: String = cleaned.chars().rev().collect();
-    cleaned == reversed
+/// Check if the characters of a string can be rearranged into a palindrome
+/// (ignoring spaces, punctuation and case): at most one character may
+/// appear an odd number of times.
+#[pyfunction]
+fn can_form_palindrome(s: &str) -> bool {
+    let mut counts: HashMap<char, usize> = HashMap::new();
+    for c in clean_alphanumeric(s).chars() {
+        *counts.entry(c).or_insert(0) += 1;
+    }
+    counts.values().filter(|&&n| n % 2 == 1).count() <= 1
+}
+
+/// Find all distinct palindromic substrings of length >= 2 by expanding
+/// around each center. Works on the raw characters (no case folding or
+/// filtering). Results are ordered by length, then by first occurrence.
+#[pyfunction]
+fn palindromic_substrings(s: &str) -> Vec<String> {
+    let chars: Vec<char> = s.chars().collect();
+    let n = chars.len();
+    // substring -> (length, first start index)
+    let mut found: HashMap<String, (usize, usize)> = HashMap::new();
+
+    for center in 0..n {
+        // Odd-length (center at a char) and even-length (center between chars)
+        for (mut lo, mut hi) in [(center, center), (center, center + 1)] {
+            while hi < n && chars[lo] == chars[hi] {
+                if hi > lo {
+                    let entry = found
+                        .entry(chars[lo..=hi].iter().collect())
+                        .or_insert((hi - lo + 1, lo));
+                    entry.1 = entry.1.min(lo);
+                }
+                if lo == 0 {
+                    break;
+                }
+                lo -= 1;
+                hi += 1;
+            }
+        }
+    }
+
+    let mut result: Vec<(String, (usize, usize))> = found.into_iter().collect();
+    result.sort_unstable_by_key(|&(_, key)| key);
+    result.into_iter().map(|(sub, _)| sub).collect()
+}
+
+/// Longest palindromic substring in O(n) using Manacher's algorithm.
+/// Works on the raw Unicode characters; when several palindromes share the
+/// maximum length, the first one is returned ("babad" -> "bab").
+#[pyfunction]
+fn longest_palindrome(s: &str) -> String {
+    let chars: Vec<char> = s.chars().collect();
+    if chars.is_empty() {
+        return String::new();
+    }
+
+    // Interleave separators so every palindrome has odd length:
+    // "abc" -> [None, a, None, b, None, c, None]
+    let mut t: Vec<Option<char>> = Vec::with_capacity(2 * chars.len() + 1);
+    t.push(None);
+    for &c in &chars {
+        t.push(Some(c));
+        t.push(None);
+    }
+
+    let mut radius = vec![0usize; t.len()];
+    let (mut center, mut right) = (0, 0);
+    let (mut best_center, mut best_radius) = (0, 0);
+    for i in 0..t.len() {
+        if i < right {
+            radius[i] = radius[2 * center - i].min(right - i);
+        }
+        while i > radius[i]
+            && i + radius[i] + 1 < t.len()
+            && t[i - radius[i] - 1] == t[i + radius[i] + 1]
+        {
+            radius[i] += 1;
+        }
+        if i + radius[i] > right {
+            center = i;
+            right = i + radius[i];
+        }
+        if radius[i] > best_radius {
+            best_center = i;
+            best_radius = radius[i];
+        }
+    }
+
+    let start = (best_center - best_radius) / 2;
+    chars[start..start + best_radius].iter().collect()
 }
 
 // ============================================================================
 // EXAMPLE 2: Error Handling
 // ============================================================================
 
+// Custom exception hierarchy. RustDemoError subclasses ValueError, so callers
+// that already catch ValueError keep working while new code can be specific.
+create_exception!(
+    rust_demo,
+    RustDemoError,
+    PyValueError,
+    "Base class for errors raised by rust_demo."
+);
+create_exception!(
+    rust_demo,
+    DimensionMismatch,
+    RustDemoError,
+    "Inputs have incompatible lengths or shapes."
+);
+create_exception!(
+    rust_demo,
+    EmptyInput,
+    RustDemoError,
+    "Input has too few values for the operation."
+);
+
 /// Parse a string as an integer with proper error handling
 #[pyfunction]
 fn safe_parse_int(s: &str) -> PyResult<i64> {
@@ -62,16 +196,58 @@ fn safe_parse_int(s: &str) -> PyResult<i64> {
         .map_err(|e| PyValueError::new_err(format!("Cannot parse '{}': {}", s, e)))
 }
 
-/// Divide two numbers, raising Python exception on division by zero
+/// Divide two numbers, raising ZeroDivisionError on division by zero.
+/// Overflow is not an error: e.g. safe_divide(1e308, 1e-308) returns inf.
 #[pyfunction]
 fn safe_divide(a: f64, b: f64) -> PyResult<f64> {
     if b == 0.0 {
-        Err(PyValueError::new_err("Division by zero"))
+        Err(PyZeroDivisionError::new_err("Division by zero"))
     } else {
         Ok(a / b)
     }
 }
 
+/// Element-wise division of two equal-length lists.
+/// `on_zero` controls zero divisors: "error" raises ZeroDivisionError naming
+/// the index, "nan" yields NaN, "inf" yields the IEEE result (signed infinity,
+/// or NaN for 0/0), and "zero" yields 0.0.
+#[pyfunction]
+#[pyo3(signature = (a, b, on_zero="error"))]
+fn divide_lists(a: Vec<f64>, b: Vec<f64>, on_zero: &str) -> PyResult<Vec<f64>> {
+    if a.len() != b.len() {
+        return Err(DimensionMismatch::new_err(format!(
+            "Length mismatch: {} vs {}",
+            a.len(),
+            b.len()
+        )));
+    }
+    if !matches!(on_zero, "error" | "nan" | "inf" | "zero") {
+        return Err(PyValueError::new_err(format!(
+            "Unknown on_zero mode '{}': expected error, nan, inf or zero",
+            on_zero
+        )));
+    }
+
+    a.iter()
+        .zip(&b)
+        .enumerate()
+        .map(|(i, (&x, &y))| {
+            if y != 0.0 {
+                return Ok(x / y);
+            }
+            match on_zero {
+                "nan" => Ok(f64::NAN),
+                "inf" => Ok(x / y),
+                "zero" => Ok(0.0),
+                _ => Err(PyZeroDivisionError::new_err(format!(
+                    "Division by zero at index {}",
+                    i
+                ))),
+            }
+        })
+        .collect()
+}
+
 // ============================================================================
 // EXAMPLE 3: Working with Collections
 // ============================================================================
@@ -98,6 +274,36 @@ fn word_frequencies(words: Vec<String>) -> HashMap<String, u64> {
     freq
 }
 
+/// Like `word_frequencies`, but returns (word, count) pairs sorted by word
+/// so the output order is deterministic
+#[pyfunction]
+fn word_frequencies_sorted(words: Vec<String>) -> Vec<(String, u64)> {
+    let mut entries: Vec<(String, u64)> = word_frequencies(words).into_iter().collect();
+    entries.sort_unstable_by(|a, b| a.0.cmp(&b.0));
+    entries
+}
+
+/// Remove stop words (case-insensitive), keeping the order of the rest
+#[pyfunction]
+fn remove_stopwords(words: Vec<String>, stopwords: Vec<String>) -> Vec<String> {
+    let stop: HashSet<String> = stopwords.iter().map(|w| w.to_lowercase()).collect();
+    words
+        .into_iter()
+        .filter(|w| !stop.contains(&w.to_lowercase()))
+        .collect()
+}
+
+/// Term frequency: each word's count divided by the total number of words
+/// (case-insensitive), so the values sum to 1.0. Empty input gives an empty map.
+#[pyfunction]
+fn term_frequency(words: Vec<String>) -> HashMap<String, f64> {
+    let total = words.len() as f64;
+    word_frequencies(words)
+        .into_iter()
+        .map(|(word, count)| (word, count as f64 / total))
+        .collect()
+}
+
 // ============================================================================
 // EXAMPLE 4: A Python Class Implemented in Rust
 // ============================================================================
@@ -145,11 +351,31 @@ impl MovingAverage {
         }
     }
 
+    /// Get the median of the current window (mean of the middle two for even counts)
+    fn median(&self) -> f64 {
+        if self.values.is_empty() {
+            return 0.0;
+        }
+        let mut sorted = self.values.clone();
+        sorted.sort_by(|a, b| a.total_cmp(b));
+        let mid = sorted.len() / 2;
+        if sorted.len().is_multiple_of(2) {
+            (sorted[mid - 1] + sorted[mid]) / 2.0
+        } else {
+            sorted[mid]
+        }
+    }
+
     /// Get the number of values currently stored
     fn count(&self) -> usize {
         self.values.len()
     }
 
+    /// Get a copy of the values in the current window, oldest first
+    fn values(&self) -> Vec<f64> {
+        self.values.clone()
+    }
+
     /// Clear all values
     fn clear(&mut self) {
         self.values.clear();
@@ -253,13 +479,21 @@ impl RingBuffer {
 /// Sum a list of integers using rayon parallel iterators with GIL released
 #[pyfunction]
 fn parallel_sum(py: Python<'_>, items: Vec<i64>) -> i64 {
-    py.allow_threads(|| items.par_iter().sum())
+    let start = Instant::now();
+    let total = py.allow_threads(|| items.par_iter().sum());
+    debug!(
+        "parallel_sum: {} items in {:?}",
+        items.len(),
+        start.elapsed()
+    );
+    total
 }
 
 /// Sieve of Eratosthenes — returns all primes up to n
 #[pyfunction]
 fn prime_sieve(py: Python<'_>, n: usize) -> Vec<usize> {
-    py.allow_threads(|| {
+    let start = Instant::now();
+    let primes = py.allow_threads(|| {
         if n < 2 {
             return vec![];
         }
@@ -281,32 +515,100 @@ fn prime_sieve(py: Python<'_>, n: usize) -> Vec<usize> {
             .enumerate()
             .filter_map(|(i, &prime)| if prime { Some(i) } else { None })
             .collect()
-    })
+    });
+    debug!(
+        "prime_sieve: {} primes up to {} in {:?}",
+        primes.len(),
+        n,
+        start.elapsed()
+    );
+    primes
+}
+
+/// Bit-packed sieve over odd numbers only: bit k stands for 2k + 1 and is set
+/// when that number is composite (1 counts as composite). Uses n/16 bytes,
+/// a sixteenth of a `Vec<bool>` sieve.
+fn odd_composite_bits(n: usize) -> Vec<u64> {
+    let len = n.div_ceil(2);
+    let mut bits = vec![0u64; len.div_ceil(64).max(1)];
+    bits[0] |= 1;
+    let mut i = 3;
+    while i * i <= n {
+        if odd_is_prime(&bits, i) {
+            let mut j = i * i;
+            while j <= n {
+                bits[j / 128] |= 1 << ((j / 2) % 64);
+                j += 2 * i;
+            }
+        }
+        i += 2;
+    }
+    bits
+}
+
+/// Look up an odd number i in an `odd_composite_bits` sieve
+fn odd_is_prime(bits: &[u64], i: usize) -> bool {
+    bits[i / 128] & (1 << ((i / 2) % 64)) == 0
 }
 
-/// Count primes up to n (same sieve, but returns only the count)
+/// Count primes up to n using a bit-packed sieve of odd numbers.
 /// Demonstrates boundary-crossing cost: returning a count is much cheaper
 /// than returning 1M items across the Rust→Python boundary.
 #[pyfunction]
 fn count_primes(py: Python<'_>, n: usize) -> usize {
+    py.allow_threads(|| sieve_count(n))
+}
+
+fn sieve_count(n: usize) -> usize {
+    if n < 2 {
+        return 0;
+    }
+    let composites: usize = odd_composite_bits(n)
+        .iter()
+        .map(|w| w.count_ones() as usize)
+        .sum();
+    // Odd primes plus the even prime 2
+    n.div_ceil(2) - composites + 1
+}
+
+/// Inclusive prefix sum (cumulative sum) computed in parallel with GIL released.
+/// Two-pass blocked scan: each block is summed in parallel, block offsets are
+/// scanned sequentially, then each block is scanned in parallel from its offset.
+#[pyfunction]
+fn parallel_cumsum(py: Python<'_>, items: Vec<f64>) -> Vec<f64> {
     py.allow_threads(|| {
-        if n < 2 {
-            return 0;
+        let mut result = items;
+        if result.is_empty() {
+            return result;
         }
-        let mut is_prime = vec![true; n + 1];
-        is_prime[0] = false;
-        is_prime[1] = false;
-        let limit = (n as f64).sqrt() as usize;
-        for i in 2..=limit {
-            if is_prime[i] {
-                let mut j = i * i;
-                while j <= n {
-                    is_prime[j] = false;
-                    j += i;
-                }
-            }
+        let chunk_size = result
+            .len()
+            .div_ceil(rayon::current_num_threads() * 4)
+            .max(1024);
+
+        let block_sums: Vec<f64> = result
+            .par_chunks(chunk_size)
+            .map(|chunk| chunk.iter().sum())
+            .collect();
+
+        let mut offsets = Vec::with_capacity(block_sums.len());
+        let mut running = 0.0;
+        for sum in block_sums {
+            offsets.push(running);
+            running += sum;
         }
-        is_prime.iter().filter(|&&p| p).count()
+
+        result
+            .par_chunks_mut(chunk_size)
+            .zip(offsets)
+            .for_each(|(chunk, offset)| {
+                let mut acc = offset;
+                for x in chunk.iter_mut() {
+                    acc += *x;
+                    *x = acc;
+                }
+            });
+        result
     })
 }
 
@@ -326,29 +628,79 @@ fn matrix_multiply(
     cols_b: usize,
 ) -> PyResult<Vec<f64>> {
     if a.len() != rows_a * cols_a {
-        return Err(PyValueError::new_err(format!(
-            "Matrix A size mismatch: expected {} elements, got {}",
-            rows_a * cols_a,
-            a.len()
-        )));
+        return size_mismatch(py, "Matrix A", rows_a * cols_a, a.len());
     }
     if b.len() != cols_a * cols_b {
-        return Err(PyValueError::new_err(format!(
-            "Matrix B size mismatch: expected {} elements, got {}",
-            cols_a * cols_b,
-            b.len()
-        )));
+        return size_mismatch(py, "Matrix B", cols_a * cols_b, b.len());
+    }
+
+    let start = Instant::now();
+    let result = py.allow_threads(|| multiply_kernel(&a, &b, rows_a, cols_a, cols_b));
+    debug!(
+        "matrix_multiply: {}x{} by {}x{} in {:?}",
+        rows_a,
+        cols_a,
+        cols_a,
+        cols_b,
+        start.elapsed()
+    );
+    Ok(result)
+}
+
+/// Raise DimensionMismatch for a wrongly sized flat matrix, with the element
+/// counts attached as `expected` and `actual` attributes for programmatic use
+fn size_mismatch<T>(py: Python<'_>, what: &str, expected: usize, actual: usize) -> PyResult<T> {
+    let err = DimensionMismatch::new_err(format!(
+        "{} size mismatch: expected {} elements, got {}",
+        what, expected, actual
+    ));
+    let value = err.value(py);
+    value.setattr("expected", expected)?;
+    value.setattr("actual", actual)?;
+    Err(err)
+}
+
+/// Row-major matrix product kernel shared by the matrix functions
+fn multiply_kernel(a: &[f64], b: &[f64], rows_a: usize, cols_a: usize, cols_b: usize) -> Vec<f64> {
+    let mut result = vec![0.0; rows_a * cols_b];
+    // Cache-friendly i-k-j ordering
+    for i in 0..rows_a {
+        for k in 0..cols_a {
+            let a_ik = a[i * cols_a + k];
+            for j in 0..cols_b {
+                result[i * cols_b + j] += a_ik * b[k * cols_b + j];
+            }
+        }
+    }
+    result
+}
+
+/// Raise an n×n matrix (flat, row-major) to a non-negative integer power
+/// using exponentiation by squaring: O(log exp) multiplications.
+/// exp == 0 returns the identity matrix.
+#[pyfunction]
+fn matrix_power(py: Python<'_>, a: Vec<f64>, n: usize, exp: u64) -> PyResult<Vec<f64>> {
+    let size = n
+        .checked_mul(n)
+        .ok_or_else(|| PyOverflowError::new_err(format!("Matrix dimension {} is too large", n)))?;
+    if a.len() != size {
+        return size_mismatch(py, "Matrix", size, a.len());
     }
 
     Ok(py.allow_threads(|| {
-        let mut result = vec![0.0; rows_a * cols_b];
-        // Cache-friendly i-k-j ordering
-        for i in 0..rows_a {
-            for k in 0..cols_a {
-                let a_ik = a[i * cols_a + k];
-                for j in 0..cols_b {
-                    result[i * cols_b + j] += a_ik * b[k * cols_b + j];
-                }
+        let mut result = vec![0.0; size];
+        for i in 0..n {
+            result[i * n + i] = 1.0;
+        }
+        let mut base = a;
+        let mut exp = exp;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = multiply_kernel(&result, &base, n, n, n);
+            }
+            exp >>= 1;
+            if exp > 0 {
+                base = multiply_kernel(&base, &base, n, n, n);
             }
         }
         result
@@ -431,6 +783,243 @@ fn extract_emails(text: &str) -> Vec<String> {
     emails
 }
 
+/// Decode `%XX` escapes and `+` (as space) in a query-string component.
+/// Malformed escapes are kept literally; invalid UTF-8 is replaced.
+fn percent_decode(s: &str) -> String {
+    let bytes = s.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'+' => out.push(b' '),
+            b'%' if i + 2 < bytes.len() => {
+                // from_str_radix alone would also accept a sign, e.g. "%+1"
+                let hex = std::str::from_utf8(&bytes[i + 1..i + 3])
+                    .ok()
+                    .filter(|h| h.bytes().all(|b| b.is_ascii_hexdigit()));
+                match hex.and_then(|h| u8::from_str_radix(h, 16).ok()) {
+                    Some(byte) => {
+                        out.push(byte);
+                        i += 2;
+                    }
+                    None => out.push(b'%'),
+                }
+            }
+            b => out.push(b),
+        }
+        i += 1;
+    }
+    String::from_utf8_lossy(&out).into_owned()
+}
+
+/// Parse a URL query string into a dict of lists.
+/// Repeated keys collect all their values ("a=1&a=2" -> {"a": ["1", "2"]});
+/// a key without '=' gets an empty-string value.
+#[pyfunction]
+fn parse_query(query: &str) -> HashMap<String, Vec<String>> {
+    let mut params: HashMap<String, Vec<String>> = HashMap::new();
+    let query = query.strip_prefix('?').unwrap_or(query);
+
+    for pair in query.split('&').filter(|p| !p.is_empty()) {
+        let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+        params
+            .entry(percent_decode(key))
+            .or_default()
+            .push(percent_decode(value));
+    }
+    params
+}
+
+/// Escape &, <, >, " and ' as HTML entities
+#[pyfunction]
+fn html_escape(text: &str) -> String {
+    ::html_escape::encode_quoted_attribute(text).into_owned()
+}
+
+/// Convert named (&amp;) and numeric (&#169;, &#xA9;) HTML entities back to text
+#[pyfunction]
+fn html_unescape(text: &str) -> String {
+    ::html_escape::decode_html_entities(text).into_owned()
+}
+
+/// Wrap text at word boundaries so no line exceeds `width` characters.
+/// Words longer than `width` go on their own line unbroken. Single newlines
+/// are treated as spaces; blank lines (paragraph breaks) are preserved.
+#[pyfunction]
+fn word_wrap(text: &str, width: usize) -> String {
+    let mut paragraphs = Vec::new();
+
+    for paragraph in text.split("\n\n") {
+        let mut lines: Vec<String> = Vec::new();
+        let mut line = String::new();
+        let mut line_len = 0;
+
+        for word in paragraph.split_whitespace() {
+            let word_len = word.chars().count();
+            if line_len > 0 && line_len + 1 + word_len > width {
+                lines.push(std::mem::take(&mut line));
+                line_len = 0;
+            }
+            if line_len > 0 {
+                line.push(' ');
+                line_len += 1;
+            }
+            line.push_str(word);
+            line_len += word_len;
+        }
+        if !line.is_empty() {
+            lines.push(line);
+        }
+        if !lines.is_empty() {
+            paragraphs.push(lines.join("\n"));
+        }
+    }
+    paragraphs.join("\n\n")
+}
+
+/// Prepend `prefix` to every line that isn't blank (like textwrap.indent)
+#[pyfunction]
+fn indent(text: &str, prefix: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    for line in text.split_inclusive('\n') {
+        if !line.trim().is_empty() {
+            result.push_str(prefix);
+        }
+        result.push_str(line);
+    }
+    result
+}
+
+/// Remove the longest common leading whitespace from every line
+/// (like textwrap.dedent). Whitespace-only lines are ignored when finding
+/// the margin and are normalized to empty lines.
+#[pyfunction]
+fn dedent(text: &str) -> String {
+    let mut margin: Option<&str> = None;
+    for line in text.lines().filter(|l| !l.trim().is_empty()) {
+        let leading = &line[..line.len() - line.trim_start().len()];
+        margin = Some(match margin {
+            None => leading,
+            Some(m) => {
+                let common = m
+                    .char_indices()
+                    .zip(leading.chars())
+                    .find(|((_, a), b)| a != b)
+                    .map_or(m.len().min(leading.len()), |((i, _), _)| i);
+                &m[..common]
+            }
+        });
+    }
+    let margin = margin.unwrap_or("");
+
+    let mut result = String::with_capacity(text.len());
+    for line in text.split_inclusive('\n') {
+        let content = line.trim_end_matches('\n');
+        if content.trim().is_empty() {
+            result.push_str(&line[content.len()..]);
+        } else {
+            result.push_str(&line[margin.len()..]);
+        }
+    }
+    result
+}
+
+/// Number of `fill` characters needed to bring `s` up to `width` graphemes
+fn padding_needed(s: &str, width: usize) -> usize {
+    width.saturating_sub(s.graphemes(true).count())
+}
+
+/// Right-align `s` to `width` graphemes by padding on the left
+#[pyfunction]
+fn pad_left(s: &str, width: usize, fill: char) -> String {
+    let pad = padding_needed(s, width);
+    std::iter::repeat_n(fill, pad).chain(s.chars()).collect()
+}
+
+/// Left-align `s` to `width` graphemes by padding on the right
+#[pyfunction]
+fn pad_right(s: &str, width: usize, fill: char) -> String {
+    let pad = padding_needed(s, width);
+    s.chars().chain(std::iter::repeat_n(fill, pad)).collect()
+}
+
+/// Center `s` in `width` graphemes. When the padding is odd, the extra
+/// fill character goes on the right.
+#[pyfunction]
+fn pad_center(s: &str, width: usize, fill: char) -> String {
+    let pad = padding_needed(s, width);
+    let left = pad / 2;
+    std::iter::repeat_n(fill, left)
+        .chain(s.chars())
+        .chain(std::iter::repeat_n(fill, pad - left))
+        .collect()
+}
+
+/// Group words that are anagrams of each other (case-insensitive).
+/// Words keep their input order within a group, and groups are ordered
+/// by the first appearance of any of their members.
+#[pyfunction]
+fn group_anagrams(words: Vec<String>) -> Vec<Vec<String>> {
+    let mut group_index: HashMap<Vec<char>, usize> = HashMap::new();
+    let mut groups: Vec<Vec<String>> = Vec::new();
+
+    for word in words {
+        let mut key: Vec<char> = word.to_lowercase().chars().collect();
+        key.sort_unstable();
+        let idx = *group_index.entry(key).or_insert_with(|| {
+            groups.push(Vec::new());
+            groups.len() - 1
+        });
+        groups[idx].push(word);
+    }
+    groups
+}
+
+/// Check if two strings are anagrams, ignoring whitespace.
+/// Comparison is case-insensitive unless `case_insensitive=False`.
+#[pyfunction]
+#[pyo3(signature = (a, b, case_insensitive=None))]
+fn is_anagram(a: &str, b: &str, case_insensitive: Option<bool>) -> bool {
+    let fold = case_insensitive.unwrap_or(true);
+    let letters = |s: &str| -> Vec<char> {
+        let mut chars: Vec<char> = if fold {
+            s.to_lowercase()
+                .chars()
+                .filter(|c| !c.is_whitespace())
+                .collect()
+        } else {
+            s.chars().filter(|c| !c.is_whitespace()).collect()
+        };
+        chars.sort_unstable();
+        chars
+    };
+    letters(a) == letters(b)
+}
+
+/// Split text on whitespace into tokens, optionally lowercasing them and
+/// stripping leading/trailing punctuation. Tokens that become empty are dropped.
+#[pyfunction]
+#[pyo3(signature = (text, lowercase=false, strip_punct=false))]
+fn tokenize(text: &str, lowercase: bool, strip_punct: bool) -> Vec<String> {
+    text.split_whitespace()
+        .map(|token| {
+            if strip_punct {
+                token.trim_matches(|c: char| c.is_ascii_punctuation())
+            } else {
+                token
+            }
+        })
+        .filter(|token| !token.is_empty())
+        .map(|token| {
+            if lowercase {
+                token.to_lowercase()
+            } else {
+                token.to_string()
+            }
+        })
+        .collect()
+}
+
 // ============================================================================
 // EXAMPLE 9: SortedSet Class
 // ============================================================================
@@ -449,6 +1038,14 @@ impl SortedSet {
         SortedSet { data: Vec::new() }
     }
 
+    /// Build a set from a list in one O(n log n) sort instead of n inserts.
+    #[staticmethod]
+    fn from_list(mut data: Vec<i64>) -> Self {
+        data.sort_unstable();
+        data.dedup();
+        SortedSet { data }
+    }
+
     /// Insert a value. Returns true if it was newly inserted.
     fn insert(&mut self, value: i64) -> bool {
         match self.data.binary_search(&value) {
@@ -494,6 +1091,16 @@ impl SortedSet {
         self.data[start..end].to_vec()
     }
 
+    /// Remove all elements.
+    fn clear(&mut self) {
+        self.data.clear();
+    }
+
+    /// Check if the set has no elements.
+    fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
     fn __len__(&self) -> usize {
         self.data.len()
     }
@@ -502,6 +1109,18 @@ impl SortedSet {
         self.contains(value)
     }
 
+    fn __eq__(&self, other: &Self) -> bool {
+        self.data == other.data
+    }
+
+    /// Hash of the current contents. Like a frozenset, a set must not be
+    /// mutated while it is stored in a Python set or used as a dict key.
+    fn __hash__(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.data.hash(&mut hasher);
+        hasher.finish()
+    }
+
     fn __repr__(&self) -> String {
         if self.data.len() <= 10 {
             format!("SortedSet({:?})", self.data)
@@ -527,35 +1146,2968 @@ fn sha256_hex(data: &str) -> String {
     format!("{:x}", hasher.finalize())
 }
 
+/// Count the differing bits between two equal-length byte strings
+#[pyfunction]
+fn hamming_distance_bytes(a: &[u8], b: &[u8]) -> PyResult<u64> {
+    if a.len() != b.len() {
+        return Err(DimensionMismatch::new_err(format!(
+            "Length mismatch: {} vs {} bytes",
+            a.len(),
+            b.len()
+        )));
+    }
+    Ok(a.iter()
+        .zip(b)
+        .map(|(x, y)| (x ^ y).count_ones() as u64)
+        .sum())
+}
+
+/// Count the differing bits between two 64-bit integers
+#[pyfunction]
+fn hamming_distance_u64(a: u64, b: u64) -> u32 {
+    (a ^ b).count_ones()
+}
+
+/// 64-bit SimHash of a token list for near-duplicate detection.
+/// Each token's hash votes +1/-1 on every bit; the sign of each total becomes
+/// the output bit. Similar documents give SimHashes with a small Hamming distance.
+#[pyfunction]
+fn simhash(tokens: Vec<String>) -> u64 {
+    let mut votes = [0i64; 64];
+    for token in &tokens {
+        let (hash, _) = hash_pair(token.as_bytes());
+        for (bit, vote) in votes.iter_mut().enumerate() {
+            if hash >> bit & 1 == 1 {
+                *vote += 1;
+            } else {
+                *vote -= 1;
+            }
+        }
+    }
+    votes
+        .iter()
+        .enumerate()
+        .filter(|&(_, &v)| v > 0)
+        .fold(0u64, |acc, (bit, _)| acc | 1 << bit)
+}
+
 // ============================================================================
-// MODULE DEFINITION
+// EXAMPLE 11: Probabilistic Data Structures
 // ============================================================================
 
-/// A Python module demonstrating Rust-Python integration with PyO3
-#[pymodule]
-fn rust_demo(m: &Bound<'_, PyModule>) -> PyResult<()> {
-    // Add functions
-    m.add_function(wrap_pyfunction!(fibonacci, m)?)?;
-    m.add_function(wrap_pyfunction!(count_unique_words, m)?)?;
-    m.add_function(wrap_pyfunction!(is_palindrome, m)?)?;
-    m.add_function(wrap_pyfunction!(safe_parse_int, m)?)?;
-    m.add_function(wrap_pyfunction!(safe_divide, m)?)?;
-    m.add_function(wrap_pyfunction!(sum_list, m)?)?;
-    m.add_function(wrap_pyfunction!(filter_positive, m)?)?;
-    m.add_function(wrap_pyfunction!(word_frequencies, m)?)?;
+/// Two independent 64-bit hashes of `item`, taken from one SHA-256 digest.
+/// Combined as `h1 + i * h2` they give as many hash functions as needed
+/// (Kirsch–Mitzenmacher double hashing).
+fn hash_pair(item: &[u8]) -> (u64, u64) {
+    let digest = Sha256::digest(item);
+    let h1 = u64::from_le_bytes(digest[0..8].try_into().unwrap());
+    let h2 = u64::from_le_bytes(digest[8..16].try_into().unwrap());
+    (h1, h2)
+}
 
-    m.add_function(wrap_pyfunction!(parallel_sum, m)?)?;
-    m.add_function(wrap_pyfunction!(prime_sieve, m)?)?;
-    m.add_function(wrap_pyfunction!(count_primes, m)?)?;
-    m.add_function(wrap_pyfunction!(matrix_multiply, m)?)?;
-    m.add_function(wrap_pyfunction!(slugify, m)?)?;
-    m.add_function(wrap_pyfunction!(extract_emails, m)?)?;
-    m.add_function(wrap_pyfunction!(sha256_hex, m)?)?;
+/// A Bloom filter for memory-bounded membership testing.
+/// `contains` may return false positives (at roughly the configured rate)
+/// but never false negatives: an added item is always reported present.
+#[pyclass]
+struct BloomFilter {
+    bits: Vec<u64>,
+    num_bits: u64,
+    num_hashes: u32,
+}
 
-    // Add classes
-    m.add_class::<MovingAverage>()?;
-    m.add_class::<RingBuffer>()?;
-    m.add_class::<SortedSet>()?;
+impl BloomFilter {
+    fn bit_positions(&self, item: &[u8]) -> impl Iterator<Item = u64> + '_ {
+        let (h1, h2) = hash_pair(item);
+        (0..self.num_hashes as u64)
+            .map(move |i| h1.wrapping_add(i.wrapping_mul(h2)) % self.num_bits)
+    }
+}
 
-    Ok(())
+#[pymethods]
+impl BloomFilter {
+    /// Create a filter sized for `expected_items` at `false_positive_rate`
+    #[new]
+    fn new(expected_items: usize, false_positive_rate: f64) -> PyResult<Self> {
+        if expected_items == 0 {
+            return Err(PyValueError::new_err("Expected items must be positive"));
+        }
+        if !(false_positive_rate > 0.0 && false_positive_rate < 1.0) {
+            return Err(PyValueError::new_err(
+                "False positive rate must be between 0 and 1",
+            ));
+        }
+        // Optimal sizing: m = -n ln(p) / (ln 2)^2, k = (m / n) ln 2
+        let n = expected_items as f64;
+        let ln2 = std::f64::consts::LN_2;
+        let num_bits = (-n * false_positive_rate.ln() / (ln2 * ln2)).ceil() as u64;
+        let num_hashes = ((num_bits as f64 / n) * ln2).round().max(1.0) as u32;
+        Ok(BloomFilter {
+            bits: vec![0; num_bits.div_ceil(64) as usize],
+            num_bits,
+            num_hashes,
+        })
+    }
+
+    /// Add an item (bytes) to the filter
+    fn add(&mut self, item: &[u8]) {
+        let positions: Vec<u64> = self.bit_positions(item).collect();
+        for pos in positions {
+            self.bits[(pos / 64) as usize] |= 1 << (pos % 64);
+        }
+    }
+
+    /// Check if an item may have been added (false positives possible)
+    fn contains(&self, item: &[u8]) -> bool {
+        self.bit_positions(item)
+            .all(|pos| self.bits[(pos / 64) as usize] & (1 << (pos % 64)) != 0)
+    }
+
+    fn __contains__(&self, item: &[u8]) -> bool {
+        self.contains(item)
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "BloomFilter(bits={}, hashes={})",
+            self.num_bits, self.num_hashes
+        )
+    }
+}
+
+/// A count-min sketch for approximate frequency counting over streams.
+/// Estimates never undercount; they may overcount due to hash collisions.
+/// Use this instead of `word_frequencies` when an exact map is too big.
+#[pyclass]
+struct CountMinSketch {
+    width: usize,
+    depth: usize,
+    counts: Vec<u64>,
+}
+
+impl CountMinSketch {
+    fn cell_indices(&self, item: &[u8]) -> impl Iterator<Item = usize> + '_ {
+        let (h1, h2) = hash_pair(item);
+        (0..self.depth).map(move |row| {
+            let col = h1.wrapping_add((row as u64).wrapping_mul(h2)) % self.width as u64;
+            row * self.width + col as usize
+        })
+    }
+}
+
+#[pymethods]
+impl CountMinSketch {
+    /// Create a sketch with `depth` rows of `width` counters each
+    #[new]
+    fn new(width: usize, depth: usize) -> PyResult<Self> {
+        if width == 0 || depth == 0 {
+            return Err(PyValueError::new_err("Width and depth must be positive"));
+        }
+        let cells = width
+            .checked_mul(depth)
+            .ok_or_else(|| PyValueError::new_err("Width * depth is too large"))?;
+        Ok(CountMinSketch {
+            width,
+            depth,
+            counts: vec![0; cells],
+        })
+    }
+
+    /// Record `count` occurrences of an item (bytes)
+    #[pyo3(signature = (item, count=1))]
+    fn add(&mut self, item: &[u8], count: u64) {
+        let cells: Vec<usize> = self.cell_indices(item).collect();
+        for cell in cells {
+            self.counts[cell] = self.counts[cell].saturating_add(count);
+        }
+    }
+
+    /// Estimate how many times an item was added (never an underestimate)
+    fn estimate(&self, item: &[u8]) -> u64 {
+        self.cell_indices(item)
+            .map(|cell| self.counts[cell])
+            .min()
+            .unwrap_or(0)
+    }
+
+    fn __repr__(&self) -> String {
+        format!("CountMinSketch(width={}, depth={})", self.width, self.depth)
+    }
+}
+
+/// A HyperLogLog cardinality estimator.
+/// Estimates the number of distinct items in a stream using 2^precision
+/// small registers instead of storing every item like `count_unique_words`.
+/// The standard error is about 1.04 / sqrt(2^precision).
+#[pyclass]
+struct HyperLogLog {
+    precision: u32,
+    registers: Vec<u8>,
+}
+
+#[pymethods]
+impl HyperLogLog {
+    /// Create an estimator with 2^precision registers (precision 4..=18)
+    #[new]
+    #[pyo3(signature = (precision=14))]
+    fn new(precision: u32) -> PyResult<Self> {
+        if !(4..=18).contains(&precision) {
+            return Err(PyValueError::new_err("Precision must be between 4 and 18"));
+        }
+        Ok(HyperLogLog {
+            precision,
+            registers: vec![0; 1 << precision],
+        })
+    }
+
+    /// Add an item (bytes) to the estimator
+    fn add(&mut self, item: &[u8]) {
+        let (hash, _) = hash_pair(item);
+        let index = (hash >> (64 - self.precision)) as usize;
+        // Rank of the first set bit in the remaining bits (1-based)
+        let rest = hash << self.precision;
+        let rank = (rest.leading_zeros().min(64 - self.precision) + 1) as u8;
+        if rank > self.registers[index] {
+            self.registers[index] = rank;
+        }
+    }
+
+    /// Estimated number of distinct items added
+    fn count(&self) -> f64 {
+        let m = self.registers.len() as f64;
+        let alpha = match self.registers.len() {
+            16 => 0.673,
+            32 => 0.697,
+            64 => 0.709,
+            _ => 0.7213 / (1.0 + 1.079 / m),
+        };
+        let sum: f64 = self.registers.iter().map(|&r| 2f64.powi(-(r as i32))).sum();
+        let estimate = alpha * m * m / sum;
+
+        // Small-range bias correction: fall back to linear counting
+        let zeros = self.registers.iter().filter(|&&r| r == 0).count();
+        if estimate <= 2.5 * m && zeros > 0 {
+            m * (m / zeros as f64).ln()
+        } else {
+            estimate
+        }
+    }
+
+    /// Merge another estimator into this one (union of both streams)
+    fn merge(slf: &Bound<'_, Self>, other: &Bound<'_, Self>) -> PyResult<()> {
+        if slf.is(other) {
+            return Ok(());
+        }
+        let other = other.borrow();
+        let mut this = slf.borrow_mut();
+        if this.precision != other.precision {
+            return Err(PyValueError::new_err(format!(
+                "Precision mismatch: {} vs {}",
+                this.precision, other.precision
+            )));
+        }
+        for (mine, &theirs) in this.registers.iter_mut().zip(&other.registers) {
+            *mine = (*mine).max(theirs);
+        }
+        Ok(())
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "HyperLogLog(precision={}, estimate={:.0})",
+            self.precision,
+            self.count()
+        )
+    }
+}
+
+/// MinHash signatures: the fraction of matching signature slots between two
+/// sets estimates their Jaccard similarity without comparing the sets directly.
+#[pyclass]
+struct MinHasher {
+    num_hashes: usize,
+}
+
+#[pymethods]
+impl MinHasher {
+    /// Create a hasher producing signatures of `num_hashes` values
+    #[new]
+    fn new(num_hashes: usize) -> PyResult<Self> {
+        if num_hashes == 0 {
+            return Err(PyValueError::new_err("Number of hashes must be positive"));
+        }
+        Ok(MinHasher { num_hashes })
+    }
+
+    /// Signature of a set of items: the minimum of each hash function over the set
+    fn signature(&self, py: Python<'_>, items: Vec<i64>) -> Vec<u64> {
+        let num_hashes = self.num_hashes;
+        py.allow_threads(|| {
+            let mut signature = vec![u64::MAX; num_hashes];
+            for item in items {
+                let (h1, h2) = hash_pair(&item.to_le_bytes());
+                for (i, slot) in signature.iter_mut().enumerate() {
+                    let h = h1.wrapping_add((i as u64).wrapping_mul(h2));
+                    *slot = (*slot).min(h);
+                }
+            }
+            signature
+        })
+    }
+
+    #[getter]
+    fn num_hashes(&self) -> usize {
+        self.num_hashes
+    }
+
+    fn __repr__(&self) -> String {
+        format!("MinHasher(num_hashes={})", self.num_hashes)
+    }
+}
+
+/// Estimated Jaccard similarity: the fraction of positions where two MinHash signatures agree
+#[pyfunction]
+fn estimate_jaccard(sig_a: Vec<u64>, sig_b: Vec<u64>) -> PyResult<f64> {
+    if sig_a.len() != sig_b.len() {
+        return Err(DimensionMismatch::new_err(format!(
+            "Signature length mismatch: {} vs {}",
+            sig_a.len(),
+            sig_b.len()
+        )));
+    }
+    if sig_a.is_empty() {
+        return Err(EmptyInput::new_err("Signatures must not be empty"));
+    }
+    let matches = sig_a.iter().zip(&sig_b).filter(|(a, b)| a == b).count();
+    Ok(matches as f64 / sig_a.len() as f64)
+}
+
+// ============================================================================
+// EXAMPLE 12: Classic Data Structures
+// ============================================================================
+
+const NIL: usize = usize::MAX;
+
+struct LruNode {
+    key: i64,
+    value: i64,
+    prev: usize,
+    next: usize,
+}
+
+/// A least-recently-used cache mapping integer keys to integer values.
+/// A HashMap indexes into a doubly-linked list of nodes stored in a Vec,
+/// so get and put are both O(1).
+#[pyclass]
+struct LruCache {
+    capacity: usize,
+    map: HashMap<i64, usize>,
+    nodes: Vec<LruNode>,
+    head: usize, // most recently used
+    tail: usize, // least recently used
+}
+
+impl LruCache {
+    fn unlink(&mut self, idx: usize) {
+        let (prev, next) = (self.nodes[idx].prev, self.nodes[idx].next);
+        if prev == NIL {
+            self.head = next;
+        } else {
+            self.nodes[prev].next = next;
+        }
+        if next == NIL {
+            self.tail = prev;
+        } else {
+            self.nodes[next].prev = prev;
+        }
+    }
+
+    fn push_front(&mut self, idx: usize) {
+        self.nodes[idx].prev = NIL;
+        self.nodes[idx].next = self.head;
+        if self.head != NIL {
+            self.nodes[self.head].prev = idx;
+        }
+        self.head = idx;
+        if self.tail == NIL {
+            self.tail = idx;
+        }
+    }
+}
+
+#[pymethods]
+impl LruCache {
+    #[new]
+    fn new(capacity: usize) -> PyResult<Self> {
+        if capacity == 0 {
+            return Err(PyValueError::new_err("Capacity must be positive"));
+        }
+        Ok(LruCache {
+            capacity,
+            map: HashMap::with_capacity(capacity),
+            nodes: Vec::with_capacity(capacity),
+            head: NIL,
+            tail: NIL,
+        })
+    }
+
+    /// Get a value, marking the key as most recently used
+    fn get(&mut self, key: i64) -> Option<i64> {
+        let idx = *self.map.get(&key)?;
+        self.unlink(idx);
+        self.push_front(idx);
+        Some(self.nodes[idx].value)
+    }
+
+    /// Insert or update a value, evicting the least recently used key if full
+    fn put(&mut self, key: i64, value: i64) {
+        if let Some(&idx) = self.map.get(&key) {
+            self.nodes[idx].value = value;
+            self.unlink(idx);
+            self.push_front(idx);
+            return;
+        }
+
+        let idx = if self.nodes.len() < self.capacity {
+            self.nodes.push(LruNode {
+                key,
+                value,
+                prev: NIL,
+                next: NIL,
+            });
+            self.nodes.len() - 1
+        } else {
+            // Reuse the evicted node's slot
+            let idx = self.tail;
+            self.unlink(idx);
+            self.map.remove(&self.nodes[idx].key);
+            self.nodes[idx].key = key;
+            self.nodes[idx].value = value;
+            idx
+        };
+        self.map.insert(key, idx);
+        self.push_front(idx);
+    }
+
+    /// Return keys from most to least recently used
+    fn keys(&self) -> Vec<i64> {
+        let mut keys = Vec::with_capacity(self.map.len());
+        let mut idx = self.head;
+        while idx != NIL {
+            keys.push(self.nodes[idx].key);
+            idx = self.nodes[idx].next;
+        }
+        keys
+    }
+
+    fn __len__(&self) -> usize {
+        self.map.len()
+    }
+
+    fn __contains__(&self, key: i64) -> bool {
+        self.map.contains_key(&key)
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "LruCache(capacity={}, len={})",
+            self.capacity,
+            self.map.len()
+        )
+    }
+}
+
+#[derive(Default)]
+struct TrieNode {
+    children: BTreeMap<char, usize>,
+    terminal: bool,
+}
+
+/// A prefix tree for autocompletion.
+/// Nodes live in a Vec and children are kept in a BTreeMap, so walking the
+/// tree depth-first yields words in sorted order for free.
+#[pyclass]
+struct Trie {
+    nodes: Vec<TrieNode>,
+    len: usize,
+}
+
+impl Trie {
+    /// Follow `prefix` from the root, returning the node it ends on
+    fn find_node(&self, prefix: &str) -> Option<usize> {
+        let mut idx = 0;
+        for ch in prefix.chars() {
+            idx = *self.nodes[idx].children.get(&ch)?;
+        }
+        Some(idx)
+    }
+
+    /// Append every word in the subtree at `idx` to `out`, in sorted order.
+    /// `prefix` spells the path to `idx`. Uses an explicit stack so very long
+    /// words cannot overflow the native one.
+    fn collect_words(&self, idx: usize, prefix: &str, out: &mut Vec<String>) {
+        let mut word = prefix.to_string();
+        // (node, char on the edge into it, length of `word` at its parent)
+        let mut stack = vec![(idx, None, word.len())];
+        while let Some((node, ch, parent_len)) = stack.pop() {
+            word.truncate(parent_len);
+            if let Some(ch) = ch {
+                word.push(ch);
+            }
+            if self.nodes[node].terminal {
+                out.push(word.clone());
+            }
+            // Reversed so the smallest child is popped first
+            for (&ch, &child) in self.nodes[node].children.iter().rev() {
+                stack.push((child, Some(ch), word.len()));
+            }
+        }
+    }
+}
+
+#[pymethods]
+impl Trie {
+    #[new]
+    fn new() -> Self {
+        Trie {
+            nodes: vec![TrieNode::default()],
+            len: 0,
+        }
+    }
+
+    /// Insert a word. Returns true if it was newly inserted.
+    fn insert(&mut self, word: &str) -> bool {
+        let mut idx = 0;
+        for ch in word.chars() {
+            idx = match self.nodes[idx].children.get(&ch) {
+                Some(&child) => child,
+                None => {
+                    self.nodes.push(TrieNode::default());
+                    let child = self.nodes.len() - 1;
+                    self.nodes[idx].children.insert(ch, child);
+                    child
+                }
+            };
+        }
+        let newly_inserted = !self.nodes[idx].terminal;
+        self.nodes[idx].terminal = true;
+        if newly_inserted {
+            self.len += 1;
+        }
+        newly_inserted
+    }
+
+    /// Check if the exact word was inserted
+    fn contains(&self, word: &str) -> bool {
+        self.find_node(word)
+            .is_some_and(|idx| self.nodes[idx].terminal)
+    }
+
+    /// Check if any inserted word starts with `prefix`
+    fn starts_with(&self, prefix: &str) -> bool {
+        self.find_node(prefix).is_some()
+    }
+
+    /// Return all inserted words starting with `prefix`, sorted
+    fn words_with_prefix(&self, prefix: &str) -> Vec<String> {
+        let mut words = Vec::new();
+        if let Some(idx) = self.find_node(prefix) {
+            self.collect_words(idx, prefix, &mut words);
+        }
+        words
+    }
+
+    fn __len__(&self) -> usize {
+        self.len
+    }
+
+    fn __contains__(&self, word: &str) -> bool {
+        self.contains(word)
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Trie(len={})", self.len)
+    }
+}
+
+/// Disjoint-set forest with union by rank and path compression,
+/// giving near-constant amortized time per operation.
+#[pyclass]
+struct UnionFind {
+    parent: Vec<usize>,
+    rank: Vec<u8>,
+    components: usize,
+}
+
+impl UnionFind {
+    fn check_index(&self, x: usize) -> PyResult<()> {
+        if x >= self.parent.len() {
+            return Err(PyIndexError::new_err(format!(
+                "Index {} out of range for {} elements",
+                x,
+                self.parent.len()
+            )));
+        }
+        Ok(())
+    }
+
+    fn root(&mut self, x: usize) -> usize {
+        let mut root = x;
+        while self.parent[root] != root {
+            root = self.parent[root];
+        }
+        // Path compression: point every node on the path at the root
+        let mut node = x;
+        while self.parent[node] != root {
+            let next = self.parent[node];
+            self.parent[node] = root;
+            node = next;
+        }
+        root
+    }
+}
+
+#[pymethods]
+impl UnionFind {
+    /// Create `n` singleton sets labelled 0..n
+    #[new]
+    fn new(n: usize) -> Self {
+        UnionFind {
+            parent: (0..n).collect(),
+            rank: vec![0; n],
+            components: n,
+        }
+    }
+
+    /// Merge the sets containing a and b. Returns true if they were separate.
+    fn union(&mut self, a: usize, b: usize) -> PyResult<bool> {
+        self.check_index(a)?;
+        self.check_index(b)?;
+        let (ra, rb) = (self.root(a), self.root(b));
+        if ra == rb {
+            return Ok(false);
+        }
+        match self.rank[ra].cmp(&self.rank[rb]) {
+            std::cmp::Ordering::Less => self.parent[ra] = rb,
+            std::cmp::Ordering::Greater => self.parent[rb] = ra,
+            std::cmp::Ordering::Equal => {
+                self.parent[rb] = ra;
+                self.rank[ra] += 1;
+            }
+        }
+        self.components -= 1;
+        Ok(true)
+    }
+
+    /// Return the representative element of x's set
+    fn find(&mut self, x: usize) -> PyResult<usize> {
+        self.check_index(x)?;
+        Ok(self.root(x))
+    }
+
+    /// Check if a and b are in the same set
+    fn connected(&mut self, a: usize, b: usize) -> PyResult<bool> {
+        Ok(self.find(a)? == self.find(b)?)
+    }
+
+    /// Number of disjoint sets
+    fn count(&self) -> usize {
+        self.components
+    }
+
+    fn __len__(&self) -> usize {
+        self.parent.len()
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "UnionFind(len={}, components={})",
+            self.parent.len(),
+            self.components
+        )
+    }
+}
+
+/// A min-heap priority queue over integers.
+/// Python's heapq is a set of functions over a plain list; this is an object.
+#[pyclass]
+struct MinHeap {
+    heap: BinaryHeap<Reverse<i64>>,
+}
+
+#[pymethods]
+impl MinHeap {
+    #[new]
+    fn new() -> Self {
+        MinHeap {
+            heap: BinaryHeap::new(),
+        }
+    }
+
+    /// Push a value onto the heap
+    fn push(&mut self, value: i64) {
+        self.heap.push(Reverse(value));
+    }
+
+    /// Remove and return the smallest value
+    fn pop(&mut self) -> Option<i64> {
+        self.heap.pop().map(|Reverse(v)| v)
+    }
+
+    /// Return the smallest value without removing it
+    fn peek(&self) -> Option<i64> {
+        self.heap.peek().map(|&Reverse(v)| v)
+    }
+
+    fn __len__(&self) -> usize {
+        self.heap.len()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("MinHeap(len={}, min={:?})", self.heap.len(), self.peek())
+    }
+}
+
+/// A last-in, first-out stack of integers
+#[pyclass]
+struct Stack {
+    items: Vec<i64>,
+}
+
+#[pymethods]
+impl Stack {
+    #[new]
+    fn new() -> Self {
+        Stack { items: Vec::new() }
+    }
+
+    /// Push a value onto the top of the stack
+    fn push(&mut self, value: i64) {
+        self.items.push(value);
+    }
+
+    /// Remove and return the top value
+    fn pop(&mut self) -> Option<i64> {
+        self.items.pop()
+    }
+
+    /// Return the top value without removing it
+    fn peek(&self) -> Option<i64> {
+        self.items.last().copied()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    fn __len__(&self) -> usize {
+        self.items.len()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Stack({:?})", self.items)
+    }
+}
+
+/// A first-in, first-out queue of integers backed by a VecDeque
+#[pyclass]
+struct Queue {
+    items: VecDeque<i64>,
+}
+
+#[pymethods]
+impl Queue {
+    #[new]
+    fn new() -> Self {
+        Queue {
+            items: VecDeque::new(),
+        }
+    }
+
+    /// Add a value to the back of the queue
+    fn enqueue(&mut self, value: i64) {
+        self.items.push_back(value);
+    }
+
+    /// Remove and return the value at the front
+    fn dequeue(&mut self) -> Option<i64> {
+        self.items.pop_front()
+    }
+
+    /// Return the front value without removing it
+    fn front(&self) -> Option<i64> {
+        self.items.front().copied()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    fn __len__(&self) -> usize {
+        self.items.len()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Queue({:?})", self.items)
+    }
+}
+
+/// A Fenwick tree (binary indexed tree) over integers.
+/// Supports point updates and prefix sums in O(log n), unlike a plain
+/// cumulative-sum array which must be rebuilt after every update.
+#[pyclass]
+struct FenwickTree {
+    // 1-based internal tree; tree[0] is unused
+    tree: Vec<i64>,
+}
+
+impl FenwickTree {
+    fn check_index(&self, index: usize) -> PyResult<()> {
+        if index >= self.tree.len() - 1 {
+            return Err(PyIndexError::new_err(format!(
+                "Index {} out of range for {} elements",
+                index,
+                self.tree.len() - 1
+            )));
+        }
+        Ok(())
+    }
+
+    fn overflow() -> PyErr {
+        PyOverflowError::new_err("Fenwick tree sum exceeds i64")
+    }
+}
+
+#[pymethods]
+impl FenwickTree {
+    /// Build a tree from initial values in O(n)
+    #[new]
+    fn new(values: Vec<i64>) -> PyResult<Self> {
+        let n = values.len();
+        let mut tree = vec![0; n + 1];
+        tree[1..].copy_from_slice(&values);
+        for i in 1..=n {
+            let parent = i + (i & i.wrapping_neg());
+            if parent <= n {
+                tree[parent] = tree[parent]
+                    .checked_add(tree[i])
+                    .ok_or_else(Self::overflow)?;
+            }
+        }
+        Ok(FenwickTree { tree })
+    }
+
+    /// Add `delta` to the element at `index`
+    fn update(&mut self, index: usize, delta: i64) -> PyResult<()> {
+        self.check_index(index)?;
+        // Check every affected node first so an overflow leaves the tree unchanged
+        let mut updated = Vec::new();
+        let mut i = index + 1;
+        while i < self.tree.len() {
+            updated.push((
+                i,
+                self.tree[i].checked_add(delta).ok_or_else(Self::overflow)?,
+            ));
+            i += i & i.wrapping_neg();
+        }
+        for (i, value) in updated {
+            self.tree[i] = value;
+        }
+        Ok(())
+    }
+
+    /// Sum of elements in [0, index]
+    fn prefix_sum(&self, index: usize) -> PyResult<i64> {
+        self.check_index(index)?;
+        let mut sum: i64 = 0;
+        let mut i = index + 1;
+        while i > 0 {
+            sum = sum.checked_add(self.tree[i]).ok_or_else(Self::overflow)?;
+            i -= i & i.wrapping_neg();
+        }
+        Ok(sum)
+    }
+
+    /// Sum of elements in [low, high] inclusive
+    fn range_sum(&self, low: usize, high: usize) -> PyResult<i64> {
+        if low > high {
+            return Err(PyValueError::new_err(format!(
+                "Invalid range: low ({}) > high ({})",
+                low, high
+            )));
+        }
+        let upper = self.prefix_sum(high)?;
+        let lower = if low == 0 {
+            0
+        } else {
+            self.prefix_sum(low - 1)?
+        };
+        upper.checked_sub(lower).ok_or_else(Self::overflow)
+    }
+
+    fn __len__(&self) -> usize {
+        self.tree.len() - 1
+    }
+
+    fn __repr__(&self) -> String {
+        format!("FenwickTree(len={})", self.tree.len() - 1)
+    }
+}
+
+/// A set of closed integer intervals [start, end] supporting overlap queries.
+/// Intervals are kept sorted by start; intervals that merely touch
+/// (e.g. [1, 3] and [3, 5]) count as overlapping.
+#[pyclass]
+struct IntervalSet {
+    intervals: Vec<(i64, i64)>,
+}
+
+impl IntervalSet {
+    /// Stored intervals that could overlap [start, end]: those starting at or before `end`
+    fn candidates(&self, end: i64) -> &[(i64, i64)] {
+        let cutoff = self.intervals.partition_point(|&(s, _)| s <= end);
+        &self.intervals[..cutoff]
+    }
+}
+
+#[pymethods]
+impl IntervalSet {
+    #[new]
+    fn new() -> Self {
+        IntervalSet {
+            intervals: Vec::new(),
+        }
+    }
+
+    /// Add the interval [start, end]
+    fn add(&mut self, start: i64, end: i64) -> PyResult<()> {
+        if start > end {
+            return Err(PyValueError::new_err(format!(
+                "Invalid interval: start ({}) > end ({})",
+                start, end
+            )));
+        }
+        let pos = self.intervals.partition_point(|&iv| iv <= (start, end));
+        self.intervals.insert(pos, (start, end));
+        Ok(())
+    }
+
+    /// Check if any stored interval overlaps [start, end]
+    fn overlaps(&self, start: i64, end: i64) -> bool {
+        self.candidates(end).iter().any(|&(_, e)| e >= start)
+    }
+
+    /// Return all stored intervals overlapping [start, end], sorted by start
+    fn query(&self, start: i64, end: i64) -> Vec<(i64, i64)> {
+        self.candidates(end)
+            .iter()
+            .filter(|&&(_, e)| e >= start)
+            .copied()
+            .collect()
+    }
+
+    fn __len__(&self) -> usize {
+        self.intervals.len()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("IntervalSet(len={})", self.intervals.len())
+    }
+}
+
+/// Sliding-window minimum and maximum over the last `window_size` values.
+/// Monotonic deques of (position, value) make both queries amortized O(1).
+#[pyclass]
+struct RollingExtrema {
+    window_size: usize,
+    seen: usize,
+    mins: VecDeque<(usize, f64)>,
+    maxs: VecDeque<(usize, f64)>,
+}
+
+#[pymethods]
+impl RollingExtrema {
+    #[new]
+    fn new(window_size: usize) -> PyResult<Self> {
+        if window_size == 0 {
+            return Err(PyValueError::new_err("Window size must be positive"));
+        }
+        Ok(RollingExtrema {
+            window_size,
+            seen: 0,
+            mins: VecDeque::new(),
+            maxs: VecDeque::new(),
+        })
+    }
+
+    /// Add a value, evicting the one that falls out of the window
+    fn add(&mut self, value: f64) {
+        let pos = self.seen;
+        self.seen += 1;
+        // Drop values that can never be the extremum again
+        while self.mins.back().is_some_and(|&(_, v)| v >= value) {
+            self.mins.pop_back();
+        }
+        while self.maxs.back().is_some_and(|&(_, v)| v <= value) {
+            self.maxs.pop_back();
+        }
+        self.mins.push_back((pos, value));
+        self.maxs.push_back((pos, value));
+        // Drop values older than the window
+        let oldest = self.seen.saturating_sub(self.window_size);
+        while self.mins.front().is_some_and(|&(p, _)| p < oldest) {
+            self.mins.pop_front();
+        }
+        while self.maxs.front().is_some_and(|&(p, _)| p < oldest) {
+            self.maxs.pop_front();
+        }
+    }
+
+    /// Minimum of the current window, or None if nothing was added
+    fn min(&self) -> Option<f64> {
+        self.mins.front().map(|&(_, v)| v)
+    }
+
+    /// Maximum of the current window, or None if nothing was added
+    fn max(&self) -> Option<f64> {
+        self.maxs.front().map(|&(_, v)| v)
+    }
+
+    fn __repr__(&self) -> String {
+        match (self.min(), self.max()) {
+            (Some(min), Some(max)) => format!(
+                "RollingExtrema(window_size={}, min={}, max={})",
+                self.window_size, min, max
+            ),
+            _ => format!("RollingExtrema(window_size={}, empty)", self.window_size),
+        }
+    }
+}
+
+// ============================================================================
+// EXAMPLE 13: Graph Algorithms
+// ============================================================================
+
+/// Ensure an edge endpoint refers to one of the `n` nodes
+fn check_node(node: usize, n: usize) -> PyResult<()> {
+    if node >= n {
+        return Err(PyValueError::new_err(format!(
+            "Node {} out of range for graph with {} nodes",
+            node, n
+        )));
+    }
+    Ok(())
+}
+
+/// `(value, index)` heap entry ordered so BinaryHeap pops the smallest value,
+/// larger index first on ties. Used by Dijkstra and as argtop_k's eviction heap.
+#[derive(PartialEq)]
+struct DistNode(f64, usize);
+
+impl Eq for DistNode {}
+
+impl Ord for DistNode {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        other.0.total_cmp(&self.0).then(self.1.cmp(&other.1))
+    }
+}
+
+impl PartialOrd for DistNode {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Shortest distances from `source` to every node of a directed graph.
+/// `edges` are (from, to, weight) triples; unreachable nodes get infinity.
+#[pyfunction]
+fn dijkstra(
+    py: Python<'_>,
+    n: usize,
+    edges: Vec<(usize, usize, f64)>,
+    source: usize,
+) -> PyResult<Vec<f64>> {
+    check_node(source, n)?;
+    let mut adjacency: Vec<Vec<(usize, f64)>> = vec![Vec::new(); n];
+    for &(from, to, weight) in &edges {
+        check_node(from, n)?;
+        check_node(to, n)?;
+        if weight.is_nan() || weight < 0.0 {
+            return Err(PyValueError::new_err(format!(
+                "Edge ({}, {}) has invalid weight {}: weights must be non-negative",
+                from, to, weight
+            )));
+        }
+        adjacency[from].push((to, weight));
+    }
+
+    Ok(py.allow_threads(|| {
+        let mut dist = vec![f64::INFINITY; n];
+        let mut heap = BinaryHeap::new();
+        dist[source] = 0.0;
+        heap.push(DistNode(0.0, source));
+
+        while let Some(DistNode(d, node)) = heap.pop() {
+            if d > dist[node] {
+                continue; // stale entry
+            }
+            for &(next, weight) in &adjacency[node] {
+                let candidate = d + weight;
+                if candidate < dist[next] {
+                    dist[next] = candidate;
+                    heap.push(DistNode(candidate, next));
+                }
+            }
+        }
+        dist
+    }))
+}
+
+/// Topologically sort a directed graph using Kahn's algorithm.
+/// Raises ValueError if the graph contains a cycle.
+#[pyfunction]
+fn topological_sort(n: usize, edges: Vec<(usize, usize)>) -> PyResult<Vec<usize>> {
+    let mut adjacency: Vec<Vec<usize>> = vec![Vec::new(); n];
+    let mut in_degree = vec![0usize; n];
+    for &(from, to) in &edges {
+        check_node(from, n)?;
+        check_node(to, n)?;
+        adjacency[from].push(to);
+        in_degree[to] += 1;
+    }
+
+    let mut ready: VecDeque<usize> = (0..n).filter(|&v| in_degree[v] == 0).collect();
+    let mut order = Vec::with_capacity(n);
+    while let Some(node) = ready.pop_front() {
+        order.push(node);
+        for &next in &adjacency[node] {
+            in_degree[next] -= 1;
+            if in_degree[next] == 0 {
+                ready.push_back(next);
+            }
+        }
+    }
+
+    if order.len() < n {
+        return Err(PyValueError::new_err(
+            "Graph contains a cycle: it is not a DAG",
+        ));
+    }
+    Ok(order)
+}
+
+/// Label each node of an undirected graph with its component id.
+/// Ids are consecutive integers starting at 0, assigned in order of each
+/// component's lowest-numbered node.
+#[pyfunction]
+fn connected_components(n: usize, edges: Vec<(usize, usize)>) -> PyResult<Vec<usize>> {
+    let mut uf = UnionFind::new(n);
+    for &(a, b) in &edges {
+        check_node(a, n)?;
+        check_node(b, n)?;
+        uf.union(a, b)?;
+    }
+
+    let mut root_labels = HashMap::new();
+    Ok((0..n)
+        .map(|node| {
+            let root = uf.root(node);
+            let next_label = root_labels.len();
+            *root_labels.entry(root).or_insert(next_label)
+        })
+        .collect())
+}
+
+// ============================================================================
+// EXAMPLE 14: NumPy Interop (zero-copy)
+// ============================================================================
+
+/// Sum a 1-D float64 NumPy array with GIL released.
+/// Reads the array's buffer in place — unlike `sum_list`, nothing is copied
+/// into a Vec first, so large arrays cost no extra allocation or conversion.
+#[pyfunction]
+fn sum_numpy(py: Python<'_>, arr: PyReadonlyArray1<f64>) -> f64 {
+    let view = arr.as_array();
+    py.allow_threads(|| view.sum())
+}
+
+/// Scale a 1-D float64 NumPy array to unit L2 norm, in place.
+/// The caller's array is modified directly — no copy in either direction.
+#[pyfunction]
+fn normalize_inplace(py: Python<'_>, mut arr: PyReadwriteArray1<f64>) -> PyResult<()> {
+    let mut view = arr.as_array_mut();
+    py.allow_threads(|| {
+        let norm = view.iter().map(|x| x * x).sum::<f64>().sqrt();
+        if norm == 0.0 {
+            return Err(PyValueError::new_err("Cannot normalize a zero vector"));
+        }
+        view.mapv_inplace(|x| x / norm);
+        Ok(())
+    })
+}
+
+/// Apply a math function element-wise, returning a new NumPy array.
+/// `op` is one of "sqrt", "exp", "log", "sin", "cos", "abs". Like NumPy,
+/// out-of-domain inputs (e.g. log of a negative) produce NaN rather than raising.
+#[pyfunction]
+fn apply_ufunc(
+    py: Python<'_>,
+    arr: PyReadonlyArray1<f64>,
+    op: &str,
+) -> PyResult<Py<PyArray1<f64>>> {
+    let func: fn(f64) -> f64 = match op {
+        "sqrt" => f64::sqrt,
+        "exp" => f64::exp,
+        "log" => f64::ln,
+        "sin" => f64::sin,
+        "cos" => f64::cos,
+        "abs" => f64::abs,
+        _ => {
+            return Err(PyValueError::new_err(format!(
+                "Unknown op '{}': expected one of sqrt, exp, log, sin, cos, abs",
+                op
+            )))
+        }
+    };
+    let view = arr.as_array();
+    let result = py.allow_threads(|| view.mapv(func));
+    Ok(result.into_pyarray(py).unbind())
+}
+
+/// Trailing moving average over a NumPy array — the vectorized cousin of
+/// `MovingAverage`. Only full windows are emitted, so the result has
+/// `len - window + 1` elements (same as `np.convolve(a, np.ones(w) / w, "valid")`).
+#[pyfunction]
+fn moving_average_np(
+    py: Python<'_>,
+    arr: PyReadonlyArray1<f64>,
+    window: usize,
+) -> PyResult<Py<PyArray1<f64>>> {
+    let view = arr.as_array();
+    if window == 0 || window > view.len() {
+        return Err(PyValueError::new_err(format!(
+            "Window must be between 1 and the array length ({}), got {}",
+            view.len(),
+            window
+        )));
+    }
+
+    let result = py.allow_threads(|| {
+        let mut out = Vec::with_capacity(view.len() - window + 1);
+        let mut running: f64 = view.iter().take(window).sum();
+        out.push(running / window as f64);
+        for i in window..view.len() {
+            running += view[i] - view[i - window];
+            out.push(running / window as f64);
+        }
+        out
+    });
+    Ok(result.into_pyarray(py).unbind())
+}
+
+/// Euclidean distance matrix between the rows of a 2-D NumPy array.
+/// Rows of the upper triangle are computed in parallel with GIL released,
+/// then mirrored; the diagonal is zero.
+#[pyfunction]
+fn pairwise_distances(
+    py: Python<'_>,
+    points: PyReadonlyArray2<f64>,
+) -> PyResult<Py<PyArray2<f64>>> {
+    let view = points.as_array();
+    let n = view.nrows();
+
+    let result = py.allow_threads(|| {
+        let mut dist = vec![0.0; n * n];
+        dist.par_chunks_mut(n.max(1))
+            .enumerate()
+            .for_each(|(i, row)| {
+                for (j, cell) in row.iter_mut().enumerate().skip(i + 1) {
+                    *cell = view
+                        .row(i)
+                        .iter()
+                        .zip(view.row(j).iter())
+                        .map(|(a, b)| (a - b) * (a - b))
+                        .sum::<f64>()
+                        .sqrt();
+                }
+            });
+        for i in 0..n {
+            for j in 0..i {
+                dist[i * n + j] = dist[j * n + i];
+            }
+        }
+        dist
+    });
+
+    let matrix =
+        Array2::from_shape_vec((n, n), result).map_err(|e| PyValueError::new_err(e.to_string()))?;
+    Ok(matrix.into_pyarray(py).unbind())
+}
+
+/// Primes up to n as a uint64 NumPy array — the array-returning twin of
+/// `prime_sieve`. The Rust Vec is handed to NumPy as-is, so no Python int
+/// objects or list are created, which matters for millions of primes.
+#[pyfunction]
+fn prime_sieve_np(py: Python<'_>, n: usize) -> Py<PyArray1<u64>> {
+    let primes = py.allow_threads(|| {
+        if n < 2 {
+            return vec![];
+        }
+        let bits = odd_composite_bits(n);
+        let mut primes = vec![2u64];
+        primes.extend(
+            (3..=n)
+                .step_by(2)
+                .filter(|&i| odd_is_prime(&bits, i))
+                .map(|i| i as u64),
+        );
+        primes
+    });
+    primes.into_pyarray(py).unbind()
+}
+
+// ============================================================================
+// EXAMPLE 15: Encodings and Ciphers
+// ============================================================================
+
+const MORSE_TABLE: &[(char, &str)] = &[
+    ('A', ".-"),
+    ('B', "-..."),
+    ('C', "-.-."),
+    ('D', "-.."),
+    ('E', "."),
+    ('F', "..-."),
+    ('G', "--."),
+    ('H', "...."),
+    ('I', ".."),
+    ('J', ".---"),
+    ('K', "-.-"),
+    ('L', ".-.."),
+    ('M', "--"),
+    ('N', "-."),
+    ('O', "---"),
+    ('P', ".--."),
+    ('Q', "--.-"),
+    ('R', ".-."),
+    ('S', "..."),
+    ('T', "-"),
+    ('U', "..-"),
+    ('V', "...-"),
+    ('W', ".--"),
+    ('X', "-..-"),
+    ('Y', "-.--"),
+    ('Z', "--.."),
+    ('0', "-----"),
+    ('1', ".----"),
+    ('2', "..---"),
+    ('3', "...--"),
+    ('4', "....-"),
+    ('5', "....."),
+    ('6', "-...."),
+    ('7', "--..."),
+    ('8', "---.."),
+    ('9', "----."),
+    ('.', ".-.-.-"),
+    (',', "--..--"),
+    ('?', "..--.."),
+    ('\'', ".----."),
+    ('!', "-.-.--"),
+    ('(', "-.--."),
+    (')', "-.--.-"),
+    ('&', ".-..."),
+    (':', "---..."),
+    (';', "-.-.-."),
+    ('=', "-...-"),
+    ('+', ".-.-."),
+    ('-', "-....-"),
+    ('"', ".-..-."),
+    ('@', ".--.-."),
+];
+
+/// Encode text as Morse code: letters separated by spaces, words by " / ".
+/// Input is case-insensitive; characters with no Morse equivalent are skipped.
+#[pyfunction]
+fn to_morse(text: &str) -> String {
+    text.split_whitespace()
+        .map(|word| {
+            word.chars()
+                .filter_map(|c| {
+                    let c = c.to_ascii_uppercase();
+                    MORSE_TABLE
+                        .iter()
+                        .find(|&&(ch, _)| ch == c)
+                        .map(|&(_, code)| code)
+                })
+                .collect::<Vec<_>>()
+                .join(" ")
+        })
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<_>>()
+        .join(" / ")
+}
+
+/// Decode Morse code (letters separated by spaces, words by "/") to uppercase text
+#[pyfunction]
+fn from_morse(code: &str) -> PyResult<String> {
+    let mut words = Vec::new();
+    for word in code.split('/') {
+        let mut decoded = String::new();
+        for symbol in word.split_whitespace() {
+            let ch = MORSE_TABLE
+                .iter()
+                .find(|&&(_, c)| c == symbol)
+                .map(|&(ch, _)| ch)
+                .ok_or_else(|| {
+                    PyValueError::new_err(format!("Unknown Morse sequence '{}'", symbol))
+                })?;
+            decoded.push(ch);
+        }
+        if !decoded.is_empty() {
+            words.push(decoded);
+        }
+    }
+    Ok(words.join(" "))
+}
+
+/// ROT47: rotate printable ASCII ('!'..='~') by 47 positions.
+/// Applying it twice returns the original text; other characters pass through.
+#[pyfunction]
+fn rot47(text: &str) -> String {
+    text.chars()
+        .map(|c| match c {
+            '!'..='~' => (b'!' + (c as u8 - b'!' + 47) % 94) as char,
+            _ => c,
+        })
+        .collect()
+}
+
+/// Shared Vigenère implementation. Only ASCII letters are shifted (case is
+/// preserved); other characters pass through without consuming the key.
+fn vigenere(text: &str, key: &str, decrypt: bool) -> PyResult<String> {
+    if key.is_empty() || !key.chars().all(|c| c.is_ascii_alphabetic()) {
+        return Err(PyValueError::new_err(
+            "Key must be a non-empty string of ASCII letters",
+        ));
+    }
+    let shifts: Vec<u8> = key.bytes().map(|b| b.to_ascii_uppercase() - b'A').collect();
+    let mut key_pos = 0;
+
+    Ok(text
+        .chars()
+        .map(|c| {
+            if !c.is_ascii_alphabetic() {
+                return c;
+            }
+            let base = if c.is_ascii_uppercase() { b'A' } else { b'a' };
+            let shift = shifts[key_pos % shifts.len()];
+            key_pos += 1;
+            let offset = c as u8 - base;
+            let rotated = if decrypt {
+                (offset + 26 - shift) % 26
+            } else {
+                (offset + shift) % 26
+            };
+            (base + rotated) as char
+        })
+        .collect())
+}
+
+/// Encrypt text with the Vigenère cipher
+#[pyfunction]
+fn vigenere_encrypt(text: &str, key: &str) -> PyResult<String> {
+    vigenere(text, key, false)
+}
+
+/// Decrypt Vigenère-encrypted text
+#[pyfunction]
+fn vigenere_decrypt(text: &str, key: &str) -> PyResult<String> {
+    vigenere(text, key, true)
+}
+
+/// XOR each byte with a repeating key. Symmetric: the same call decrypts.
+#[pyfunction]
+fn xor_cipher(data: &[u8], key: &[u8]) -> PyResult<Vec<u8>> {
+    if key.is_empty() {
+        return Err(PyValueError::new_err("Key must not be empty"));
+    }
+    Ok(data
+        .iter()
+        .zip(key.iter().cycle())
+        .map(|(d, k)| d ^ k)
+        .collect())
+}
+
+// ============================================================================
+// EXAMPLE 16: Statistics
+// ============================================================================
+
+/// Most frequent value(s) of a list, sorted ascending (several on a tie)
+#[pyfunction]
+fn mode_ints(items: Vec<i64>) -> PyResult<Vec<i64>> {
+    if items.is_empty() {
+        return Err(EmptyInput::new_err("Cannot compute mode of an empty list"));
+    }
+    let mut counts: HashMap<i64, usize> = HashMap::new();
+    for &x in &items {
+        *counts.entry(x).or_insert(0) += 1;
+    }
+    let max_count = counts.values().copied().max().unwrap_or(0);
+    let mut modes: Vec<i64> = counts
+        .into_iter()
+        .filter(|&(_, c)| c == max_count)
+        .map(|(x, _)| x)
+        .collect();
+    modes.sort_unstable();
+    Ok(modes)
+}
+
+/// Validate that two paired lists have equal length of at least `min_len`
+fn check_paired(x: &[f64], y: &[f64], min_len: usize) -> PyResult<()> {
+    if x.len() != y.len() {
+        return Err(DimensionMismatch::new_err(format!(
+            "Length mismatch: {} vs {}",
+            x.len(),
+            y.len()
+        )));
+    }
+    if x.len() < min_len {
+        return Err(EmptyInput::new_err(format!(
+            "Need at least {} values, got {}",
+            min_len,
+            x.len()
+        )));
+    }
+    Ok(())
+}
+
+/// Weighted mean: sum(v * w) / sum(w)
+#[pyfunction]
+fn weighted_mean(values: Vec<f64>, weights: Vec<f64>) -> PyResult<f64> {
+    check_paired(&values, &weights, 1)?;
+    let total_weight: f64 = weights.iter().sum();
+    if total_weight == 0.0 {
+        return Err(PyValueError::new_err("Total weight must be non-zero"));
+    }
+    let weighted_sum: f64 = values.iter().zip(&weights).map(|(v, w)| v * w).sum();
+    Ok(weighted_sum / total_weight)
+}
+
+fn mean(xs: &[f64]) -> f64 {
+    xs.iter().sum::<f64>() / xs.len() as f64
+}
+
+/// Sums of squared/cross deviations from the mean: (Sxx, Sxy, Syy)
+fn centered_sums(x: &[f64], y: &[f64]) -> (f64, f64, f64) {
+    let (mean_x, mean_y) = (mean(x), mean(y));
+    x.iter()
+        .zip(y)
+        .fold((0.0, 0.0, 0.0), |(sxx, sxy, syy), (&xi, &yi)| {
+            let (dx, dy) = (xi - mean_x, yi - mean_y);
+            (sxx + dx * dx, sxy + dx * dy, syy + dy * dy)
+        })
+}
+
+/// Ordinary least squares fit y = slope * x + intercept.
+/// Returns (slope, intercept, r_squared); r_squared is 1.0 when y is constant.
+#[pyfunction]
+fn linear_regression(x: Vec<f64>, y: Vec<f64>) -> PyResult<(f64, f64, f64)> {
+    check_paired(&x, &y, 2)?;
+    let (mean_x, mean_y) = (mean(&x), mean(&y));
+    let (sxx, sxy, syy) = centered_sums(&x, &y);
+    if sxx == 0.0 {
+        return Err(PyValueError::new_err("x values must not all be equal"));
+    }
+
+    let slope = sxy / sxx;
+    let intercept = mean_y - slope * mean_x;
+    let r_squared = if syy == 0.0 {
+        1.0
+    } else {
+        let ss_res: f64 = x
+            .iter()
+            .zip(&y)
+            .map(|(&xi, &yi)| (yi - (slope * xi + intercept)).powi(2))
+            .sum();
+        1.0 - ss_res / syy
+    };
+    Ok((slope, intercept, r_squared))
+}
+
+/// Pearson correlation coefficient r in [-1, 1]
+#[pyfunction]
+fn pearson_correlation(x: Vec<f64>, y: Vec<f64>) -> PyResult<f64> {
+    check_paired(&x, &y, 2)?;
+    pearson(&x, &y)
+}
+
+fn pearson(x: &[f64], y: &[f64]) -> PyResult<f64> {
+    let (sxx, sxy, syy) = centered_sums(x, y);
+    if sxx == 0.0 || syy == 0.0 {
+        return Err(PyValueError::new_err(
+            "Standard deviation of x and y must be non-zero",
+        ));
+    }
+    // Clamp away floating-point drift just outside [-1, 1]
+    Ok((sxy / (sxx * syy).sqrt()).clamp(-1.0, 1.0))
+}
+
+/// Rescale values so the minimum maps to 0.0 and the maximum to 1.0.
+/// A constant input (all values equal) maps to all zeros.
+#[pyfunction]
+fn minmax_scale(items: Vec<f64>) -> PyResult<Vec<f64>> {
+    if items.is_empty() {
+        return Err(EmptyInput::new_err("Cannot scale an empty list"));
+    }
+    let min = items.iter().copied().fold(f64::INFINITY, f64::min);
+    let max = items.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    let range = max - min;
+    if range == 0.0 {
+        return Ok(vec![0.0; items.len()]);
+    }
+    Ok(items.iter().map(|&x| (x - min) / range).collect())
+}
+
+/// Standardize values to z-scores: (x - mean) / sample standard deviation
+#[pyfunction]
+fn zscore(items: Vec<f64>) -> PyResult<Vec<f64>> {
+    if items.len() < 2 {
+        return Err(EmptyInput::new_err(format!(
+            "Need at least 2 values, got {}",
+            items.len()
+        )));
+    }
+    let mu = mean(&items);
+    let variance =
+        items.iter().map(|&x| (x - mu) * (x - mu)).sum::<f64>() / (items.len() - 1) as f64;
+    if variance == 0.0 {
+        return Err(PyValueError::new_err("Variance must be non-zero"));
+    }
+    let std = variance.sqrt();
+    Ok(items.iter().map(|&x| (x - mu) / std).collect())
+}
+
+/// Clamp every value into [low, high] in parallel with the GIL released
+#[pyfunction]
+fn clamp_list(py: Python<'_>, items: Vec<f64>, low: f64, high: f64) -> PyResult<Vec<f64>> {
+    if low.is_nan() || high.is_nan() {
+        return Err(PyValueError::new_err("Bounds must not be NaN"));
+    }
+    if low > high {
+        return Err(PyValueError::new_err(format!(
+            "low ({}) must not exceed high ({})",
+            low, high
+        )));
+    }
+    Ok(py.allow_threads(|| items.par_iter().map(|&x| x.clamp(low, high)).collect()))
+}
+
+/// Indices of the k largest values, largest first (ties go to the smaller index).
+/// Keeps a bounded heap of k candidates instead of sorting the whole list.
+#[pyfunction]
+fn argtop_k(items: Vec<f64>, k: usize) -> PyResult<Vec<usize>> {
+    if let Some(i) = items.iter().position(|x| x.is_nan()) {
+        return Err(PyValueError::new_err(format!(
+            "Cannot rank NaN (at index {})",
+            i
+        )));
+    }
+    let mut heap = BinaryHeap::with_capacity(k.min(items.len()) + 1);
+    for (i, &x) in items.iter().enumerate() {
+        heap.push(DistNode(x, i));
+        if heap.len() > k {
+            heap.pop();
+        }
+    }
+    Ok(heap
+        .into_sorted_vec()
+        .into_iter()
+        .map(|entry| entry.1)
+        .collect())
+}
+
+/// Indices that would sort the list ascending (stable, like numpy.argsort(kind="stable"))
+#[pyfunction]
+fn argsort(items: Vec<f64>) -> PyResult<Vec<usize>> {
+    if let Some(i) = items.iter().position(|x| x.is_nan()) {
+        return Err(PyValueError::new_err(format!(
+            "Cannot sort NaN (at index {})",
+            i
+        )));
+    }
+    let mut indices: Vec<usize> = (0..items.len()).collect();
+    indices.sort_by(|&i, &j| items[i].partial_cmp(&items[j]).unwrap());
+    Ok(indices)
+}
+
+/// 1-indexed ranks with ties sharing the average of their positions
+fn average_ranks(items: &[f64]) -> Vec<f64> {
+    let mut order: Vec<usize> = (0..items.len()).collect();
+    order.sort_by(|&i, &j| items[i].total_cmp(&items[j]));
+    let mut ranks = vec![0.0; items.len()];
+    let mut start = 0;
+    while start < order.len() {
+        let mut end = start + 1;
+        while end < order.len() && items[order[end]] == items[order[start]] {
+            end += 1;
+        }
+        // Positions start..end (0-based) share rank mean(start+1..=end)
+        let avg = (start + end + 1) as f64 / 2.0;
+        for &i in &order[start..end] {
+            ranks[i] = avg;
+        }
+        start = end;
+    }
+    ranks
+}
+
+/// Rank transform (1-indexed); ties get the average rank, like scipy's "average" method
+#[pyfunction]
+fn rank(items: Vec<f64>) -> Vec<f64> {
+    average_ranks(&items)
+}
+
+/// Spearman's rho: Pearson correlation of the rank-transformed inputs
+#[pyfunction]
+fn spearman_correlation(x: Vec<f64>, y: Vec<f64>) -> PyResult<f64> {
+    check_paired(&x, &y, 2)?;
+    pearson(&average_ranks(&x), &average_ranks(&y))
+}
+
+/// Turn category counts into proportions, rejecting an all-zero total
+fn proportions(counts: &[u64]) -> PyResult<Vec<f64>> {
+    let total: u64 = counts.iter().sum();
+    if total == 0 {
+        return Err(PyValueError::new_err("Total count must be non-zero"));
+    }
+    Ok(counts.iter().map(|&c| c as f64 / total as f64).collect())
+}
+
+/// Shannon entropy in bits of a distribution given as category counts
+#[pyfunction]
+fn shannon_entropy(counts: Vec<u64>) -> PyResult<f64> {
+    Ok(entropy_bits(&proportions(&counts)?))
+}
+
+fn entropy_bits(proportions: &[f64]) -> f64 {
+    proportions
+        .iter()
+        .filter(|&&p| p > 0.0)
+        .map(|&p| p * (1.0 / p).log2())
+        .sum()
+}
+
+/// Gini impurity 1 - sum(p_i^2) of a distribution given as category counts
+#[pyfunction]
+fn gini_impurity(counts: Vec<u64>) -> PyResult<f64> {
+    Ok(1.0 - proportions(&counts)?.iter().map(|p| p * p).sum::<f64>())
+}
+
+/// Cosine similarity of two sparse vectors given as {key: weight} dicts.
+/// Returns 0.0 when either vector has zero norm.
+#[pyfunction]
+fn sparse_cosine(a: HashMap<String, f64>, b: HashMap<String, f64>) -> f64 {
+    let (small, large) = if a.len() <= b.len() {
+        (&a, &b)
+    } else {
+        (&b, &a)
+    };
+    let dot = small
+        .iter()
+        .filter_map(|(k, v)| large.get(k).map(|w| v * w))
+        .fold(0.0, |acc, x| acc + x);
+    let norm_a = a.values().map(|v| v * v).sum::<f64>().sqrt();
+    let norm_b = b.values().map(|v| v * v).sum::<f64>().sqrt();
+    if norm_a == 0.0 || norm_b == 0.0 {
+        return 0.0;
+    }
+    dot / (norm_a * norm_b)
+}
+
+/// Jaccard similarity |A ∩ B| / |A ∪ B| of two lists treated as sets.
+/// By convention two empty sets are identical (1.0).
+#[pyfunction]
+fn jaccard_similarity(a: Vec<i64>, b: Vec<i64>) -> f64 {
+    let set_a: HashSet<i64> = a.into_iter().collect();
+    let set_b: HashSet<i64> = b.into_iter().collect();
+    let union = set_a.union(&set_b).count();
+    if union == 0 {
+        return 1.0;
+    }
+    set_a.intersection(&set_b).count() as f64 / union as f64
+}
+
+// ============================================================================
+// EXAMPLE 17: Number Theory
+// ============================================================================
+
+fn mul_mod(a: u64, b: u64, m: u64) -> u64 {
+    ((a as u128 * b as u128) % m as u128) as u64
+}
+
+fn pow_mod(mut base: u64, mut exp: u64, m: u64) -> u64 {
+    let mut result = 1;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = mul_mod(result, base, m);
+        }
+        base = mul_mod(base, base, m);
+        exp >>= 1;
+    }
+    result
+}
+
+/// Deterministic Miller–Rabin primality test, exact for every u64
+fn is_prime(n: u64) -> bool {
+    const BASES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+    if n < 2 {
+        return false;
+    }
+    for &p in &BASES {
+        if n.is_multiple_of(p) {
+            return n == p;
+        }
+    }
+    // n - 1 = d * 2^r with d odd
+    let r = (n - 1).trailing_zeros();
+    let d = (n - 1) >> r;
+    'witness: for &a in &BASES {
+        let mut x = pow_mod(a, d, n);
+        if x == 1 || x == n - 1 {
+            continue;
+        }
+        for _ in 1..r {
+            x = mul_mod(x, x, n);
+            if x == n - 1 {
+                continue 'witness;
+            }
+        }
+        return false;
+    }
+    true
+}
+
+/// A non-trivial factor of an odd composite n (Pollard's rho, Floyd cycle finding)
+fn pollard_rho(n: u64) -> u64 {
+    for c in 1.. {
+        let f = |x: u64| ((x as u128 * x as u128 + c as u128) % n as u128) as u64;
+        let (mut x, mut y, mut d) = (2, 2, 1);
+        while d == 1 {
+            x = f(x);
+            y = f(f(y));
+            d = gcd(x.abs_diff(y), n);
+        }
+        if d != n {
+            return d;
+        }
+    }
+    unreachable!()
+}
+
+fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// Prime factorization of n >= 1 as ascending (prime, exponent) pairs.
+/// Small factors come from trial division, the rest from Pollard's rho.
+fn factorize(mut n: u64) -> Vec<(u64, u32)> {
+    let mut primes = Vec::new();
+    for p in 2..1000 {
+        while n.is_multiple_of(p) {
+            primes.push(p);
+            n /= p;
+        }
+    }
+    let mut pending = vec![n];
+    while let Some(m) = pending.pop() {
+        if m == 1 {
+            continue;
+        }
+        if is_prime(m) {
+            primes.push(m);
+        } else {
+            let d = pollard_rho(m);
+            pending.push(d);
+            pending.push(m / d);
+        }
+    }
+    primes.sort_unstable();
+
+    let mut factors: Vec<(u64, u32)> = Vec::new();
+    for p in primes {
+        match factors.last_mut() {
+            Some((q, exponent)) if *q == p => *exponent += 1,
+            _ => factors.push((p, 1)),
+        }
+    }
+    factors
+}
+
+/// Two primes (p, n - p) summing to an even n >= 4, with the smallest such p
+#[pyfunction]
+fn goldbach(py: Python<'_>, n: u64) -> PyResult<(u64, u64)> {
+    if n < 4 || !n.is_multiple_of(2) {
+        return Err(PyValueError::new_err(format!(
+            "Goldbach partitions need an even number >= 4, got {}",
+            n
+        )));
+    }
+    py.allow_threads(|| {
+        (2..=n / 2)
+            .find(|&p| is_prime(p) && is_prime(n - p))
+            .map(|p| (p, n - p))
+            .ok_or_else(|| PyValueError::new_err(format!("No Goldbach partition found for {}", n)))
+    })
+}
+
+/// All twin prime pairs (p, p + 2) with p + 2 <= n
+#[pyfunction]
+fn twin_primes(py: Python<'_>, n: usize) -> Vec<(usize, usize)> {
+    py.allow_threads(|| {
+        if n < 5 {
+            return vec![];
+        }
+        let bits = odd_composite_bits(n);
+        (3..=n - 2)
+            .step_by(2)
+            .filter(|&p| odd_is_prime(&bits, p) && odd_is_prime(&bits, p + 2))
+            .map(|p| (p, p + 2))
+            .collect()
+    })
+}
+
+/// Euler's totient φ(n): how many of 1..=n are coprime to n (φ(0) is taken as 0)
+#[pyfunction]
+fn totient(n: u64) -> u64 {
+    if n == 0 {
+        return 0;
+    }
+    factorize(n)
+        .into_iter()
+        .fold(n, |phi, (p, _)| phi / p * (p - 1))
+}
+
+fn check_positive(n: u64) -> PyResult<()> {
+    if n == 0 {
+        return Err(PyValueError::new_err("n must be positive"));
+    }
+    Ok(())
+}
+
+/// All divisors of n in ascending order, built from its prime factorization
+#[pyfunction]
+fn divisors(n: u64) -> PyResult<Vec<u64>> {
+    check_positive(n)?;
+    let mut result = vec![1];
+    for (p, exponent) in factorize(n) {
+        let mut next = Vec::with_capacity(result.len() * (exponent as usize + 1));
+        for &d in &result {
+            let mut power = d;
+            next.push(power);
+            for _ in 0..exponent {
+                power *= p;
+                next.push(power);
+            }
+        }
+        result = next;
+    }
+    result.sort_unstable();
+    Ok(result)
+}
+
+/// Sigma function in u128, which cannot overflow for any u64 n
+fn sigma(n: u64) -> u128 {
+    // Multiplicative: product of (p^(e+1) - 1) / (p - 1) over prime powers
+    factorize(n).into_iter().fold(1, |acc, (p, exponent)| {
+        let p = p as u128;
+        acc * ((p.pow(exponent + 1) - 1) / (p - 1))
+    })
+}
+
+/// Sum of all divisors of n (the sigma function), including n itself
+#[pyfunction]
+fn divisor_sum(n: u64) -> PyResult<u64> {
+    check_positive(n)?;
+    u64::try_from(sigma(n))
+        .map_err(|_| PyOverflowError::new_err(format!("Divisor sum of {} exceeds u64", n)))
+}
+
+/// "perfect", "abundant" or "deficient": the sum of n's proper divisors
+/// compared to n itself
+#[pyfunction]
+fn classify_number(n: u64) -> PyResult<String> {
+    check_positive(n)?;
+    let proper_sum = sigma(n) - n as u128;
+    let class = match proper_sum.cmp(&(n as u128)) {
+        std::cmp::Ordering::Equal => "perfect",
+        std::cmp::Ordering::Greater => "abundant",
+        std::cmp::Ordering::Less => "deficient",
+    };
+    Ok(class.to_string())
+}
+
+/// Continued-fraction coefficients [a0; a1, a2, ...] of numerator / denominator,
+/// via the Euclidean algorithm. a0 is the floor, so it may be negative.
+#[pyfunction]
+fn continued_fraction(numerator: i64, denominator: i64) -> PyResult<Vec<i64>> {
+    if denominator == 0 {
+        return Err(PyZeroDivisionError::new_err("Denominator must be non-zero"));
+    }
+    // i128 so negating i64::MIN to normalize the sign cannot overflow
+    let (mut p, mut q) = (numerator as i128, denominator as i128);
+    if q < 0 {
+        (p, q) = (-p, -q);
+    }
+    let mut coefficients = Vec::new();
+    loop {
+        let a = i64::try_from(p.div_euclid(q))
+            .map_err(|_| PyOverflowError::new_err("Continued-fraction coefficient exceeds i64"))?;
+        coefficients.push(a);
+        let r = p.rem_euclid(q);
+        if r == 0 {
+            return Ok(coefficients);
+        }
+        (p, q) = (q, r);
+    }
+}
+
+/// numerator / denominator in lowest terms, with a positive denominator
+#[pyfunction]
+fn reduce_fraction(numerator: i64, denominator: i64) -> PyResult<(i64, i64)> {
+    if denominator == 0 {
+        return Err(PyZeroDivisionError::new_err("Denominator must be non-zero"));
+    }
+    let g = gcd(numerator.unsigned_abs(), denominator.unsigned_abs()) as i128;
+    let sign = denominator.signum() as i128;
+    let (p, q) = (numerator as i128 / g * sign, denominator as i128 / g * sign);
+    match (i64::try_from(p), i64::try_from(q)) {
+        (Ok(p), Ok(q)) => Ok((p, q)),
+        _ => Err(PyOverflowError::new_err("Reduced fraction exceeds i64")),
+    }
+}
+
+/// Binomial coefficient C(n, k) with u128 intermediates, or None if it exceeds u64
+fn checked_binomial(n: u64, k: u64) -> Option<u64> {
+    if k > n {
+        return Some(0);
+    }
+    let k = k.min(n - k);
+    let mut result: u128 = 1;
+    // After step i, result == C(n - k + i, i), which only grows for k <= n / 2,
+    // so every intermediate fits once the final value does
+    for i in 1..=k as u128 {
+        result = result * (n as u128 - k as u128 + i) / i;
+        if result > u64::MAX as u128 {
+            return None;
+        }
+    }
+    Some(result as u64)
+}
+
+/// Binomial coefficient C(n, k); 0 when k > n
+#[pyfunction]
+fn binomial(n: u64, k: u64) -> PyResult<u64> {
+    checked_binomial(n, k)
+        .ok_or_else(|| PyOverflowError::new_err(format!("C({}, {}) exceeds u64", n, k)))
+}
+
+/// The nth Catalan number C(2n, n) / (n + 1).
+/// Uses the recurrence C(i+1) = C(i) * 2(2i + 1) / (i + 2) rather than the
+/// binomial itself, since C(2n, n) overflows u64 before the Catalan number does.
+#[pyfunction]
+fn catalan(n: u64) -> PyResult<u64> {
+    let mut c: u128 = 1;
+    for i in 0..n as u128 {
+        c = c * 2 * (2 * i + 1) / (i + 2);
+        if c > u64::MAX as u128 {
+            return Err(PyOverflowError::new_err(format!(
+                "Catalan number {} exceeds u64",
+                n
+            )));
+        }
+    }
+    Ok(c as u64)
+}
+
+// ============================================================================
+// EXAMPLE 18: Numerical Methods
+// ============================================================================
+
+/// Reverse the low `bits` bits of `value` (e.g. 0b001 over 3 bits -> 0b100)
+#[pyfunction]
+fn bit_reverse(value: u64, bits: u32) -> PyResult<u64> {
+    if bits > 64 {
+        return Err(PyValueError::new_err(format!(
+            "bits must be at most 64, got {}",
+            bits
+        )));
+    }
+    if bits < 64 && value >> bits != 0 {
+        return Err(PyValueError::new_err(format!(
+            "Value {} does not fit in {} bits",
+            value, bits
+        )));
+    }
+    Ok(value.reverse_bits().checked_shr(64 - bits).unwrap_or(0))
+}
+
+/// Discrete Fourier transform of a complex signal given as separate real and
+/// imaginary parts, by iterative radix-2 Cooley–Tukey. The length must be a
+/// power of two. Returns (real, imag) of the spectrum, unnormalized like numpy.fft.fft.
+#[pyfunction]
+fn fft(py: Python<'_>, real: Vec<f64>, imag: Vec<f64>) -> PyResult<(Vec<f64>, Vec<f64>)> {
+    if real.len() != imag.len() {
+        return Err(DimensionMismatch::new_err(format!(
+            "Length mismatch: {} vs {}",
+            real.len(),
+            imag.len()
+        )));
+    }
+    let n = real.len();
+    if !n.is_power_of_two() {
+        return Err(PyValueError::new_err(format!(
+            "Length must be a power of two, got {}",
+            n
+        )));
+    }
+
+    Ok(py.allow_threads(|| {
+        let (mut re, mut im) = (real, imag);
+        // Reorder into bit-reversed index order so butterflies can run in place
+        let bits = n.trailing_zeros();
+        for i in 0..n {
+            let j = i
+                .reverse_bits()
+                .checked_shr(usize::BITS - bits)
+                .unwrap_or(0);
+            if i < j {
+                re.swap(i, j);
+                im.swap(i, j);
+            }
+        }
+
+        let mut len = 2;
+        while len <= n {
+            let angle = -2.0 * std::f64::consts::PI / len as f64;
+            for start in (0..n).step_by(len) {
+                for k in 0..len / 2 {
+                    let (w_re, w_im) = ((angle * k as f64).cos(), (angle * k as f64).sin());
+                    let (a, b) = (start + k, start + k + len / 2);
+                    let t_re = re[b] * w_re - im[b] * w_im;
+                    let t_im = re[b] * w_im + im[b] * w_re;
+                    re[b] = re[a] - t_re;
+                    im[b] = im[a] - t_im;
+                    re[a] += t_re;
+                    im[a] += t_im;
+                }
+            }
+            len *= 2;
+        }
+        (re, im)
+    }))
+}
+
+/// Evaluate a polynomial at x by Horner's method.
+/// Coefficients run from highest to lowest degree: [1, 2, 1] is x² + 2x + 1.
+#[pyfunction]
+fn poly_eval(coeffs: Vec<f64>, x: f64) -> f64 {
+    horner(&coeffs, x)
+}
+
+fn horner(coeffs: &[f64], x: f64) -> f64 {
+    coeffs.iter().fold(0.0, |acc, &c| acc * x + c)
+}
+
+/// A real root of a polynomial (coefficients highest degree first) by
+/// Newton–Raphson from `guess`, stopping once a step is smaller than `tol`.
+#[pyfunction]
+fn poly_root(coeffs: Vec<f64>, guess: f64, tol: f64, max_iter: usize) -> PyResult<f64> {
+    if tol <= 0.0 || tol.is_nan() {
+        return Err(PyValueError::new_err("Tolerance must be positive"));
+    }
+    let degree = coeffs.len().saturating_sub(1);
+    let derivative: Vec<f64> = coeffs
+        .iter()
+        .take(degree)
+        .enumerate()
+        .map(|(i, &c)| c * (degree - i) as f64)
+        .collect();
+
+    let mut x = guess;
+    for _ in 0..max_iter {
+        let fx = horner(&coeffs, x);
+        if fx == 0.0 {
+            return Ok(x);
+        }
+        let dfx = horner(&derivative, x);
+        if dfx == 0.0 {
+            return Err(PyValueError::new_err(format!(
+                "Zero derivative at x = {}",
+                x
+            )));
+        }
+        let step = fx / dfx;
+        x -= step;
+        if !x.is_finite() {
+            break;
+        }
+        if step.abs() < tol {
+            return Ok(x);
+        }
+    }
+    Err(PyValueError::new_err(format!(
+        "Failed to converge within {} iterations",
+        max_iter
+    )))
+}
+
+/// Integrate uniformly spaced samples with composite Simpson's rule.
+/// Needs an odd number of points (>= 3), i.e. an even number of intervals.
+#[pyfunction]
+fn simpson(y: Vec<f64>, dx: f64) -> PyResult<f64> {
+    if y.len() < 3 || y.len().is_multiple_of(2) {
+        return Err(PyValueError::new_err(format!(
+            "Simpson's rule needs an odd number of points >= 3, got {}",
+            y.len()
+        )));
+    }
+    let last = y.len() - 1;
+    let interior: f64 = y[1..last]
+        .iter()
+        .enumerate()
+        .map(|(i, &v)| if i % 2 == 0 { 4.0 * v } else { 2.0 * v })
+        .sum();
+    Ok(dx / 3.0 * (y[0] + interior + y[last]))
+}
+
+/// Integrate samples with the trapezoidal rule, either at explicit
+/// (possibly non-uniform) points `x` or with uniform spacing `dx` (default 1.0).
+/// Fewer than two samples integrate to 0.0, as in numpy.trapezoid.
+#[pyfunction]
+#[pyo3(signature = (y, x=None, dx=None))]
+fn trapezoid(y: Vec<f64>, x: Option<Vec<f64>>, dx: Option<f64>) -> PyResult<f64> {
+    match (x, dx) {
+        (Some(_), Some(_)) => Err(PyValueError::new_err("Pass either x or dx, not both")),
+        (Some(x), None) => {
+            check_paired(&x, &y, 0)?;
+            Ok(x.windows(2).zip(y.windows(2)).fold(0.0, |area, (xs, ys)| {
+                area + (xs[1] - xs[0]) * (ys[0] + ys[1]) / 2.0
+            }))
+        }
+        (None, dx) => {
+            let dx = dx.unwrap_or(1.0);
+            Ok(y.windows(2)
+                .fold(0.0, |area, ys| area + dx * (ys[0] + ys[1]) / 2.0))
+        }
+    }
+}
+
+// ============================================================================
+// EXAMPLE 19: Checksums and Compression
+// ============================================================================
+
+/// CRC-32 (IEEE, as in zlib and gzip) of a byte string
+#[pyfunction]
+fn crc32(data: &[u8]) -> u32 {
+    crc32fast::hash(data)
+}
+
+/// Incremental CRC-32 for data that arrives in chunks.
+/// `finalize` works on a copy, so more data can still be added afterwards.
+#[pyclass]
+struct Crc32Hasher {
+    hasher: crc32fast::Hasher,
+}
+
+#[pymethods]
+impl Crc32Hasher {
+    #[new]
+    fn new() -> Self {
+        Crc32Hasher {
+            hasher: crc32fast::Hasher::new(),
+        }
+    }
+
+    /// Feed the next chunk of bytes
+    fn update(&mut self, data: &[u8]) {
+        self.hasher.update(data);
+    }
+
+    /// CRC-32 of everything fed so far
+    fn finalize(&self) -> u32 {
+        self.hasher.clone().finalize()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Crc32Hasher(crc={:#010x})", self.finalize())
+    }
+}
+
+/// Adler-32 checksum (as used in zlib streams) of a byte string
+#[pyfunction]
+fn adler32(data: &[u8]) -> u32 {
+    const MOD_ADLER: u32 = 65521;
+    // 5552 is the largest chunk for which the sums cannot overflow u32
+    // before being reduced
+    let (mut a, mut b) = (1u32, 0u32);
+    for chunk in data.chunks(5552) {
+        for &byte in chunk {
+            a += byte as u32;
+            b += a;
+        }
+        a %= MOD_ADLER;
+        b %= MOD_ADLER;
+    }
+    (b << 16) | a
+}
+
+/// Validate an optional 0-9 compression level (default 6, as in zlib)
+fn compression_level(level: Option<u32>) -> PyResult<flate2::Compression> {
+    match level.unwrap_or(6) {
+        level @ 0..=9 => Ok(flate2::Compression::new(level)),
+        level => Err(PyValueError::new_err(format!(
+            "Compression level must be between 0 and 9, got {}",
+            level
+        ))),
+    }
+}
+
+/// Compress bytes into the gzip format with GIL released
+#[pyfunction]
+#[pyo3(signature = (data, level=None))]
+fn gzip_compress(py: Python<'_>, data: &[u8], level: Option<u32>) -> PyResult<Vec<u8>> {
+    let level = compression_level(level)?;
+    py.allow_threads(|| {
+        let mut encoder = flate2::write::GzEncoder::new(Vec::new(), level);
+        encoder.write_all(data)?;
+        encoder.finish()
+    })
+    .map_err(|e| PyValueError::new_err(format!("Compression failed: {}", e)))
+}
+
+/// Decompress gzip-format bytes with GIL released
+#[pyfunction]
+fn gzip_decompress(py: Python<'_>, data: &[u8]) -> PyResult<Vec<u8>> {
+    py.allow_threads(|| {
+        let mut out = Vec::new();
+        flate2::read::GzDecoder::new(data).read_to_end(&mut out)?;
+        Ok(out)
+    })
+    .map_err(|e: std::io::Error| PyValueError::new_err(format!("Invalid gzip data: {}", e)))
+}
+
+/// Compress bytes into a raw deflate stream (no zlib or gzip header) with GIL released
+#[pyfunction]
+#[pyo3(signature = (data, level=None))]
+fn deflate_compress(py: Python<'_>, data: &[u8], level: Option<u32>) -> PyResult<Vec<u8>> {
+    let level = compression_level(level)?;
+    py.allow_threads(|| {
+        let mut encoder = flate2::write::DeflateEncoder::new(Vec::new(), level);
+        encoder.write_all(data)?;
+        encoder.finish()
+    })
+    .map_err(|e| PyValueError::new_err(format!("Compression failed: {}", e)))
+}
+
+/// Decompress a raw deflate stream with GIL released
+#[pyfunction]
+fn deflate_decompress(py: Python<'_>, data: &[u8]) -> PyResult<Vec<u8>> {
+    py.allow_threads(|| {
+        let mut out = Vec::new();
+        flate2::read::DeflateDecoder::new(data).read_to_end(&mut out)?;
+        Ok(out)
+    })
+    .map_err(|e: std::io::Error| PyValueError::new_err(format!("Invalid deflate data: {}", e)))
+}
+
+/// Shannon entropy of a byte string in bits per byte, from 0.0 (one repeated
+/// byte) to 8.0. Values near 8.0 suggest compressed or encrypted data.
+#[pyfunction]
+fn byte_entropy(data: &[u8]) -> f64 {
+    if data.is_empty() {
+        return 0.0;
+    }
+    let mut counts = [0u64; 256];
+    for &byte in data {
+        counts[byte as usize] += 1;
+    }
+    let total = data.len() as f64;
+    let proportions: Vec<f64> = counts.iter().map(|&c| c as f64 / total).collect();
+    entropy_bits(&proportions)
+}
+
+// ============================================================================
+// EXAMPLE 20: Identifiers
+// ============================================================================
+
+/// A random (version 4) UUID in canonical hyphenated form
+#[pyfunction]
+fn uuid4() -> String {
+    uuid::Uuid::new_v4().hyphenated().to_string()
+}
+
+/// `n` random version 4 UUIDs, generated with the GIL released
+#[pyfunction]
+fn uuid4_batch(py: Python<'_>, n: usize) -> Vec<String> {
+    py.allow_threads(|| (0..n).map(|_| uuid4()).collect())
+}
+
+/// A name-based (version 5, SHA-1) UUID: the same namespace UUID and name
+/// always produce the same result
+#[pyfunction]
+fn uuid5(namespace: &str, name: &str) -> PyResult<String> {
+    let namespace = uuid::Uuid::parse_str(namespace).map_err(|e| {
+        PyValueError::new_err(format!("Invalid namespace UUID '{}': {}", namespace, e))
+    })?;
+    Ok(uuid::Uuid::new_v5(&namespace, name.as_bytes())
+        .hyphenated()
+        .to_string())
+}
+
+/// A ULID: 48-bit millisecond timestamp plus 80 random bits, as 26 Crockford
+/// base32 characters. ULIDs sort by creation time (in random order within
+/// the same millisecond), which suits database keys.
+#[pyfunction]
+fn ulid() -> String {
+    const CROCKFORD: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+    let millis = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |d| d.as_millis() as u64)
+        & ((1 << 48) - 1);
+    let randomness = rand::random::<u128>() & ((1 << 80) - 1);
+    let value = (millis as u128) << 80 | randomness;
+    // 26 characters * 5 bits = 130 bits; the top two are always zero
+    (0..26)
+        .rev()
+        .map(|i| CROCKFORD[(value >> (i * 5)) as usize & 31] as char)
+        .collect()
+}
+
+/// A URL-safe random ID of `size` characters (default 21) from the standard
+/// nanoid alphabet, drawn from a cryptographically secure RNG
+#[pyfunction]
+#[pyo3(signature = (size=None))]
+fn nanoid(size: Option<usize>) -> String {
+    const ALPHABET: &[u8; 64] = b"useandom-26T198340PX75pxJACKVERYMINDBUSHWOLF_GQZbfghjklqvwyzrict";
+    let mut rng = rand::thread_rng();
+    // 64 symbols, so masking a random byte to 6 bits keeps the choice uniform
+    (0..size.unwrap_or(21))
+        .map(|_| ALPHABET[(rng.gen::<u8>() & 63) as usize] as char)
+        .collect()
+}
+
+// ============================================================================
+// EXAMPLE 21: Validation and Networking
+// ============================================================================
+
+/// Digits of `number` with spaces and hyphens dropped, or None on any other character
+fn separated_digits(number: &str) -> Option<Vec<u32>> {
+    number
+        .chars()
+        .filter(|&c| c != ' ' && c != '-')
+        .map(|c| c.to_digit(10))
+        .collect()
+}
+
+/// Luhn sum of `digits`, doubling every second digit from the right.
+/// `double_last` says whether the rightmost digit itself is doubled.
+fn luhn_sum(digits: &[u32], double_last: bool) -> u32 {
+    digits
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(i, &d)| {
+            if (i % 2 == 0) == double_last {
+                let doubled = d * 2;
+                if doubled > 9 {
+                    doubled - 9
+                } else {
+                    doubled
+                }
+            } else {
+                d
+            }
+        })
+        .sum()
+}
+
+/// Check a number (e.g. a card number) against its Luhn check digit.
+/// Spaces and hyphens are ignored; any other non-digit makes it invalid.
+#[pyfunction]
+fn luhn_valid(number: &str) -> bool {
+    match separated_digits(number) {
+        Some(digits) if digits.len() >= 2 => luhn_sum(&digits, false).is_multiple_of(10),
+        _ => false,
+    }
+}
+
+/// The Luhn check digit to append to `number` to make it valid
+#[pyfunction]
+fn luhn_check_digit(number: &str) -> PyResult<u8> {
+    match separated_digits(number) {
+        Some(digits) if !digits.is_empty() => Ok(((10 - luhn_sum(&digits, true) % 10) % 10) as u8),
+        _ => Err(PyValueError::new_err(format!(
+            "Expected digits (optionally separated by spaces or hyphens), got '{}'",
+            number
+        ))),
+    }
+}
+
+/// Validate an ISBN-10 (check digit may be 'X' for 10) or ISBN-13.
+/// Spaces and hyphens are ignored.
+#[pyfunction]
+fn isbn_valid(isbn: &str) -> bool {
+    let chars: Vec<char> = isbn.chars().filter(|&c| c != ' ' && c != '-').collect();
+    match chars.len() {
+        10 => {
+            let mut sum = 0;
+            for (i, &c) in chars.iter().enumerate() {
+                let value = match c {
+                    'X' | 'x' if i == 9 => 10,
+                    _ => match c.to_digit(10) {
+                        Some(d) => d,
+                        None => return false,
+                    },
+                };
+                sum += (10 - i as u32) * value;
+            }
+            sum.is_multiple_of(11)
+        }
+        13 => {
+            let Some(digits) = chars
+                .iter()
+                .map(|c| c.to_digit(10))
+                .collect::<Option<Vec<u32>>>()
+            else {
+                return false;
+            };
+            let sum: u32 = digits
+                .iter()
+                .enumerate()
+                .map(|(i, &d)| if i % 2 == 0 { d } else { 3 * d })
+                .sum();
+            sum.is_multiple_of(10)
+        }
+        _ => false,
+    }
+}
+
+/// Parse a dotted-quad IPv4 address ("192.168.0.1") into its 32-bit value
+#[pyfunction]
+fn ipv4_to_int(addr: &str) -> PyResult<u32> {
+    addr.parse::<std::net::Ipv4Addr>()
+        .map(u32::from)
+        .map_err(|_| PyValueError::new_err(format!("Invalid IPv4 address: '{}'", addr)))
+}
+
+/// Format a 32-bit value as a dotted-quad IPv4 address
+#[pyfunction]
+fn int_to_ipv4(value: u32) -> String {
+    std::net::Ipv4Addr::from(value).to_string()
+}
+
+/// Check whether an IPv4 address falls inside a CIDR range like "192.168.1.0/24".
+/// Host bits set in the network part are ignored.
+#[pyfunction]
+fn cidr_contains(cidr: &str, addr: &str) -> PyResult<bool> {
+    let invalid = || PyValueError::new_err(format!("Invalid CIDR range: '{}'", cidr));
+    let (network, prefix) = cidr.split_once('/').ok_or_else(invalid)?;
+    let prefix: u32 = prefix.parse().map_err(|_| invalid())?;
+    if prefix > 32 {
+        return Err(PyValueError::new_err(format!(
+            "Prefix length must be at most 32, got {}",
+            prefix
+        )));
+    }
+    let network = ipv4_to_int(network)?;
+    let addr = ipv4_to_int(addr)?;
+    let mask = u32::MAX.checked_shl(32 - prefix).unwrap_or(0);
+    Ok(network & mask == addr & mask)
+}
+
+// ============================================================================
+// EXAMPLE 22: Concurrency and Instrumentation
+// ============================================================================
+
+/// A thread-safe counter for handing out unique, increasing IDs.
+/// `frozen` means no borrow tracking: every method works through the atomic,
+/// so calls from many threads (even without the GIL) never conflict.
+#[pyclass(frozen)]
+struct AtomicCounter {
+    value: AtomicU64,
+}
+
+#[pymethods]
+impl AtomicCounter {
+    #[new]
+    #[pyo3(signature = (start=0))]
+    fn new(start: u64) -> Self {
+        AtomicCounter {
+            value: AtomicU64::new(start),
+        }
+    }
+
+    /// Return the current value and advance the counter by one
+    fn next(&self) -> u64 {
+        self.value.fetch_add(1, Ordering::Relaxed)
+    }
+
+    /// Read the current value without advancing it
+    fn get(&self) -> u64 {
+        self.value.load(Ordering::Relaxed)
+    }
+
+    /// Reset the counter to zero
+    fn reset(&self) {
+        self.value.store(0, Ordering::Relaxed);
+    }
+
+    fn __repr__(&self) -> String {
+        format!("AtomicCounter(value={})", self.get())
+    }
+}
+
+/// Call a Python function `iterations` times with `args` and return the mean
+/// wall-clock seconds per call. Useful for measuring boundary-crossing cost,
+/// e.g. timing a Rust function from Python against its pure-Python twin.
+#[pyfunction]
+fn time_call(
+    py: Python<'_>,
+    func: PyObject,
+    args: Py<PyTuple>,
+    iterations: usize,
+) -> PyResult<f64> {
+    if iterations == 0 {
+        return Err(PyValueError::new_err("Iterations must be positive"));
+    }
+    let args = args.bind(py);
+    let start = Instant::now();
+    for _ in 0..iterations {
+        func.call1(py, args)?;
+    }
+    Ok(start.elapsed().as_secs_f64() / iterations as f64)
+}
+
+/// A dedicated rayon thread pool scoped to a `with` block, so parallel work
+/// can use a chosen number of threads without touching the global pool.
+/// The pool is shut down on exit; using the session afterwards raises RuntimeError.
+#[pyclass]
+struct ParallelSession {
+    pool: Option<rayon::ThreadPool>,
+}
+
+impl ParallelSession {
+    fn pool(&self) -> PyResult<&rayon::ThreadPool> {
+        self.pool
+            .as_ref()
+            .ok_or_else(|| PyRuntimeError::new_err("ParallelSession is closed"))
+    }
+}
+
+#[pymethods]
+impl ParallelSession {
+    /// Create a session with `num_threads` workers (default: one per CPU)
+    #[new]
+    #[pyo3(signature = (num_threads=None))]
+    fn new(num_threads: Option<usize>) -> PyResult<Self> {
+        if num_threads == Some(0) {
+            return Err(PyValueError::new_err("Number of threads must be positive"));
+        }
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(num_threads.unwrap_or(0))
+            .build()
+            .map_err(|e| PyRuntimeError::new_err(format!("Cannot start thread pool: {}", e)))?;
+        Ok(ParallelSession { pool: Some(pool) })
+    }
+
+    /// Sum a list of integers on the session's pool with GIL released
+    fn sum(&self, py: Python<'_>, items: Vec<i64>) -> PyResult<i64> {
+        let pool = self.pool()?;
+        Ok(py.allow_threads(|| pool.install(|| items.par_iter().sum())))
+    }
+
+    /// Return a sorted copy of a list of integers, sorted on the session's pool
+    fn sort(&self, py: Python<'_>, mut items: Vec<i64>) -> PyResult<Vec<i64>> {
+        let pool = self.pool()?;
+        py.allow_threads(|| pool.install(|| items.par_sort_unstable()));
+        Ok(items)
+    }
+
+    /// Number of worker threads, or 0 once the session is closed
+    #[getter]
+    fn num_threads(&self) -> usize {
+        self.pool
+            .as_ref()
+            .map_or(0, |pool| pool.current_num_threads())
+    }
+
+    /// Shut down the thread pool
+    fn close(&mut self) {
+        self.pool = None;
+    }
+
+    fn __enter__(slf: PyRef<'_, Self>) -> PyResult<PyRef<'_, Self>> {
+        slf.pool()?;
+        Ok(slf)
+    }
+
+    #[pyo3(signature = (_exc_type=None, _exc_value=None, _traceback=None))]
+    fn __exit__(
+        &mut self,
+        _exc_type: Option<PyObject>,
+        _exc_value: Option<PyObject>,
+        _traceback: Option<PyObject>,
+    ) -> bool {
+        self.close();
+        false
+    }
+
+    fn __repr__(&self) -> String {
+        match self.pool {
+            Some(_) => format!("ParallelSession(num_threads={})", self.num_threads()),
+            None => "ParallelSession(closed)".to_string(),
+        }
+    }
+}
+
+static LOG_RESET: OnceLock<pyo3_log::ResetHandle> = OnceLock::new();
+
+/// Route the crate's Rust `log` records (e.g. debug timings from
+/// `matrix_multiply`, `prime_sieve` and `parallel_sum`) into Python's `logging`.
+/// Logger levels are cached, so call this again after reconfiguring logging.
+#[pyfunction]
+fn init_logging() -> PyResult<()> {
+    match LOG_RESET.get() {
+        Some(handle) => handle.reset(),
+        None => {
+            let handle = pyo3_log::try_init()
+                .map_err(|e| PyRuntimeError::new_err(format!("Cannot install logger: {}", e)))?;
+            let _ = LOG_RESET.set(handle);
+        }
+    }
+    Ok(())
+}
+
+// ============================================================================
+// MODULE DEFINITION
+// ============================================================================
+
+/// A Python module demonstrating Rust-Python integration with PyO3
+#[pymodule]
+fn rust_demo(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    // Add exceptions
+    m.add("RustDemoError", m.py().get_type::<RustDemoError>())?;
+    m.add("DimensionMismatch", m.py().get_type::<DimensionMismatch>())?;
+    m.add("EmptyInput", m.py().get_type::<EmptyInput>())?;
+
+    // Add functions
+    m.add_function(wrap_pyfunction!(fibonacci, m)?)?;
+    m.add_function(wrap_pyfunction!(count_unique_words, m)?)?;
+    m.add_function(wrap_pyfunction!(is_palindrome, m)?)?;
+    m.add_function(wrap_pyfunction!(can_form_palindrome, m)?)?;
+    m.add_function(wrap_pyfunction!(palindromic_substrings, m)?)?;
+    m.add_function(wrap_pyfunction!(longest_palindrome, m)?)?;
+    m.add_function(wrap_pyfunction!(safe_parse_int, m)?)?;
+    m.add_function(wrap_pyfunction!(safe_divide, m)?)?;
+    m.add_function(wrap_pyfunction!(divide_lists, m)?)?;
+    m.add_function(wrap_pyfunction!(sum_list, m)?)?;
+    m.add_function(wrap_pyfunction!(filter_positive, m)?)?;
+    m.add_function(wrap_pyfunction!(word_frequencies, m)?)?;
+    m.add_function(wrap_pyfunction!(word_frequencies_sorted, m)?)?;
+    m.add_function(wrap_pyfunction!(remove_stopwords, m)?)?;
+    m.add_function(wrap_pyfunction!(term_frequency, m)?)?;
+
+    m.add_function(wrap_pyfunction!(parallel_sum, m)?)?;
+    m.add_function(wrap_pyfunction!(prime_sieve, m)?)?;
+    m.add_function(wrap_pyfunction!(count_primes, m)?)?;
+    m.add_function(wrap_pyfunction!(parallel_cumsum, m)?)?;
+    m.add_function(wrap_pyfunction!(matrix_multiply, m)?)?;
+    m.add_function(wrap_pyfunction!(matrix_power, m)?)?;
+    m.add_function(wrap_pyfunction!(slugify, m)?)?;
+    m.add_function(wrap_pyfunction!(extract_emails, m)?)?;
+    m.add_function(wrap_pyfunction!(parse_query, m)?)?;
+    m.add_function(wrap_pyfunction!(crate::html_escape, m)?)?;
+    m.add_function(wrap_pyfunction!(html_unescape, m)?)?;
+    m.add_function(wrap_pyfunction!(word_wrap, m)?)?;
+    m.add_function(wrap_pyfunction!(indent, m)?)?;
+    m.add_function(wrap_pyfunction!(dedent, m)?)?;
+    m.add_function(wrap_pyfunction!(pad_left, m)?)?;
+    m.add_function(wrap_pyfunction!(pad_right, m)?)?;
+    m.add_function(wrap_pyfunction!(pad_center, m)?)?;
+    m.add_function(wrap_pyfunction!(group_anagrams, m)?)?;
+    m.add_function(wrap_pyfunction!(is_anagram, m)?)?;
+    m.add_function(wrap_pyfunction!(tokenize, m)?)?;
+    m.add_function(wrap_pyfunction!(sha256_hex, m)?)?;
+    m.add_function(wrap_pyfunction!(hamming_distance_bytes, m)?)?;
+    m.add_function(wrap_pyfunction!(hamming_distance_u64, m)?)?;
+    m.add_function(wrap_pyfunction!(simhash, m)?)?;
+    m.add_function(wrap_pyfunction!(dijkstra, m)?)?;
+    m.add_function(wrap_pyfunction!(topological_sort, m)?)?;
+    m.add_function(wrap_pyfunction!(connected_components, m)?)?;
+    m.add_function(wrap_pyfunction!(sum_numpy, m)?)?;
+    m.add_function(wrap_pyfunction!(normalize_inplace, m)?)?;
+    m.add_function(wrap_pyfunction!(apply_ufunc, m)?)?;
+    m.add_function(wrap_pyfunction!(moving_average_np, m)?)?;
+    m.add_function(wrap_pyfunction!(pairwise_distances, m)?)?;
+    m.add_function(wrap_pyfunction!(prime_sieve_np, m)?)?;
+    m.add_function(wrap_pyfunction!(to_morse, m)?)?;
+    m.add_function(wrap_pyfunction!(from_morse, m)?)?;
+    m.add_function(wrap_pyfunction!(rot47, m)?)?;
+    m.add_function(wrap_pyfunction!(vigenere_encrypt, m)?)?;
+    m.add_function(wrap_pyfunction!(vigenere_decrypt, m)?)?;
+    m.add_function(wrap_pyfunction!(xor_cipher, m)?)?;
+    m.add_function(wrap_pyfunction!(mode_ints, m)?)?;
+    m.add_function(wrap_pyfunction!(weighted_mean, m)?)?;
+    m.add_function(wrap_pyfunction!(linear_regression, m)?)?;
+    m.add_function(wrap_pyfunction!(pearson_correlation, m)?)?;
+    m.add_function(wrap_pyfunction!(minmax_scale, m)?)?;
+    m.add_function(wrap_pyfunction!(zscore, m)?)?;
+    m.add_function(wrap_pyfunction!(clamp_list, m)?)?;
+    m.add_function(wrap_pyfunction!(argtop_k, m)?)?;
+    m.add_function(wrap_pyfunction!(argsort, m)?)?;
+    m.add_function(wrap_pyfunction!(rank, m)?)?;
+    m.add_function(wrap_pyfunction!(spearman_correlation, m)?)?;
+    m.add_function(wrap_pyfunction!(shannon_entropy, m)?)?;
+    m.add_function(wrap_pyfunction!(gini_impurity, m)?)?;
+    m.add_function(wrap_pyfunction!(sparse_cosine, m)?)?;
+    m.add_function(wrap_pyfunction!(jaccard_similarity, m)?)?;
+    m.add_function(wrap_pyfunction!(estimate_jaccard, m)?)?;
+    m.add_function(wrap_pyfunction!(goldbach, m)?)?;
+    m.add_function(wrap_pyfunction!(twin_primes, m)?)?;
+    m.add_function(wrap_pyfunction!(totient, m)?)?;
+    m.add_function(wrap_pyfunction!(divisors, m)?)?;
+    m.add_function(wrap_pyfunction!(divisor_sum, m)?)?;
+    m.add_function(wrap_pyfunction!(classify_number, m)?)?;
+    m.add_function(wrap_pyfunction!(continued_fraction, m)?)?;
+    m.add_function(wrap_pyfunction!(reduce_fraction, m)?)?;
+    m.add_function(wrap_pyfunction!(binomial, m)?)?;
+    m.add_function(wrap_pyfunction!(catalan, m)?)?;
+    m.add_function(wrap_pyfunction!(bit_reverse, m)?)?;
+    m.add_function(wrap_pyfunction!(fft, m)?)?;
+    m.add_function(wrap_pyfunction!(poly_eval, m)?)?;
+    m.add_function(wrap_pyfunction!(poly_root, m)?)?;
+    m.add_function(wrap_pyfunction!(simpson, m)?)?;
+    m.add_function(wrap_pyfunction!(trapezoid, m)?)?;
+    m.add_function(wrap_pyfunction!(crc32, m)?)?;
+    m.add_function(wrap_pyfunction!(adler32, m)?)?;
+    m.add_function(wrap_pyfunction!(gzip_compress, m)?)?;
+    m.add_function(wrap_pyfunction!(gzip_decompress, m)?)?;
+    m.add_function(wrap_pyfunction!(deflate_compress, m)?)?;
+    m.add_function(wrap_pyfunction!(deflate_decompress, m)?)?;
+    m.add_function(wrap_pyfunction!(byte_entropy, m)?)?;
+    m.add_function(wrap_pyfunction!(uuid4, m)?)?;
+    m.add_function(wrap_pyfunction!(uuid4_batch, m)?)?;
+    m.add_function(wrap_pyfunction!(uuid5, m)?)?;
+    m.add_function(wrap_pyfunction!(ulid, m)?)?;
+    m.add_function(wrap_pyfunction!(nanoid, m)?)?;
+    m.add_function(wrap_pyfunction!(luhn_valid, m)?)?;
+    m.add_function(wrap_pyfunction!(luhn_check_digit, m)?)?;
+    m.add_function(wrap_pyfunction!(isbn_valid, m)?)?;
+    m.add_function(wrap_pyfunction!(ipv4_to_int, m)?)?;
+    m.add_function(wrap_pyfunction!(int_to_ipv4, m)?)?;
+    m.add_function(wrap_pyfunction!(cidr_contains, m)?)?;
+    m.add_function(wrap_pyfunction!(time_call, m)?)?;
+    m.add_function(wrap_pyfunction!(init_logging, m)?)?;
+
+    // Add classes
+    m.add_class::<MovingAverage>()?;
+    m.add_class::<RingBuffer>()?;
+    m.add_class::<SortedSet>()?;
+    m.add_class::<BloomFilter>()?;
+    m.add_class::<CountMinSketch>()?;
+    m.add_class::<HyperLogLog>()?;
+    m.add_class::<LruCache>()?;
+    m.add_class::<Trie>()?;
+    m.add_class::<UnionFind>()?;
+    m.add_class::<MinHeap>()?;
+    m.add_class::<Stack>()?;
+    m.add_class::<Queue>()?;
+    m.add_class::<FenwickTree>()?;
+    m.add_class::<IntervalSet>()?;
+    m.add_class::<MinHasher>()?;
+    m.add_class::<RollingExtrema>()?;
+    m.add_class::<Crc32Hasher>()?;
+    m.add_class::<AtomicCounter>()?;
+    m.add_class::<ParallelSession>()?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Plain one-byte-per-number sieve used as a reference
+    fn reference_sieve(n: usize) -> Vec<bool> {
+        let mut is_prime = vec![true; n + 1];
+        for flag in is_prime.iter_mut().take(2) {
+            *flag = false;
+        }
+        let mut i = 2;
+        while i * i <= n {
+            if is_prime[i] {
+                for j in (i * i..=n).step_by(i) {
+                    is_prime[j] = false;
+                }
+            }
+            i += 1;
+        }
+        is_prime
+    }
+
+    #[test]
+    fn bit_sieve_matches_reference_counts() {
+        // 127..=129 and 255..=257 straddle the first two 64-bit words
+        // (each word covers 128 numbers, odd ones only)
+        let sizes = [
+            0, 1, 2, 3, 4, 10, 100, 127, 128, 129, 255, 256, 257, 1000, 1_000_000, 10_000_000,
+        ];
+        for n in sizes {
+            let expected = reference_sieve(n).iter().filter(|&&p| p).count();
+            assert_eq!(sieve_count(n), expected, "n = {}", n);
+        }
+    }
+
+    #[test]
+    fn bit_sieve_matches_reference_per_number() {
+        let n = 10_000;
+        let reference = reference_sieve(n);
+        let bits = odd_composite_bits(n);
+        for i in (1..=n).step_by(2) {
+            assert_eq!(odd_is_prime(&bits, i), reference[i], "i = {}", i);
+        }
+    }
+
+    #[test]
+    fn is_prime_matches_reference_sieve() {
+        let reference = reference_sieve(100_000);
+        for (n, &expected) in reference.iter().enumerate() {
+            assert_eq!(is_prime(n as u64), expected, "n = {}", n);
+        }
+    }
+
+    #[test]
+    fn is_prime_rejects_strong_pseudoprimes() {
+        // Carmichael number 561, then the smallest strong pseudoprimes to the
+        // first k prime bases (OEIS A014233)
+        let composites = [
+            561,
+            2_047,
+            1_373_653,
+            25_326_001,
+            3_215_031_751,
+            2_152_302_898_747,
+            3_474_749_660_383,
+            341_550_071_728_321,
+            3_825_123_056_546_413_051,
+            u64::MAX,
+        ];
+        for n in composites {
+            assert!(!is_prime(n), "{} is composite", n);
+        }
+        for p in [2_305_843_009_213_693_951, 18_446_744_073_709_551_557] {
+            assert!(is_prime(p), "{} is prime", p);
+        }
+    }
 }
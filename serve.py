@@ -303,7 +303,7 @@ class DemoHandler(BaseHTTPRequestHandler):
         try:
             result = rust_demo.safe_divide(a, b)
             self.send_json({"a": a, "b": b, "result": result, "error": None})
-        except ValueError as e:
+        except ZeroDivisionError as e:
             self.send_json({"a": a, "b": b, "result": None, "error": str(e)})
 
     def handle_sum_list(self):
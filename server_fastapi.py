@@ -273,7 +273,7 @@ def divide(req: DivideRequest):
     try:
         result = rust_demo.safe_divide(req.a, req.b)
         return DivideResponse(a=req.a, b=req.b, result=result, error=None)
-    except ValueError as e:
+    except ZeroDivisionError as e:
         return DivideResponse(a=req.a, b=req.b, result=None, error=str(e))
 
 
@@ -0,0 +1,88 @@
+"""Tests for the checksum, compression and byte-entropy functions."""
+
+import gzip
+import zlib
+
+import pytest
+import rust_demo
+
+DATA = b"The quick brown fox jumps over the lazy dog. " * 200
+
+
+# crc32 / Crc32Hasher
+
+
+def test_crc32_known_value():
+    assert rust_demo.crc32(b"The quick brown fox jumps over the lazy dog") == 0x414FA339
+    assert rust_demo.crc32(DATA) == zlib.crc32(DATA)
+
+
+def test_crc32_hasher_chunked_matches_one_shot():
+    hasher = rust_demo.Crc32Hasher()
+    for start in range(0, len(DATA), 777):
+        hasher.update(DATA[start : start + 777])
+    assert hasher.finalize() == rust_demo.crc32(DATA)
+    # finalize does not consume the running state
+    assert hasher.finalize() == rust_demo.crc32(DATA)
+
+
+# adler32
+
+
+def test_adler32_known_value():
+    assert rust_demo.adler32(b"Wikipedia") == 0x11E60398
+    assert rust_demo.adler32(b"") == 1
+
+
+def test_adler32_past_the_5552_byte_chunk():
+    data = bytes(range(256)) * 100 + b"\xff" * 6000
+    assert len(data) > 5552
+    assert rust_demo.adler32(data) == zlib.adler32(data)
+
+
+# gzip / deflate
+
+
+def test_gzip_round_trip_and_shrink():
+    compressed = rust_demo.gzip_compress(DATA)
+    assert len(compressed) < len(DATA) // 10
+    assert bytes(rust_demo.gzip_decompress(compressed)) == DATA
+    # Interoperates with the standard library in both directions
+    assert gzip.decompress(bytes(compressed)) == DATA
+    assert bytes(rust_demo.gzip_decompress(gzip.compress(DATA))) == DATA
+
+
+def test_gzip_levels():
+    fast = rust_demo.gzip_compress(DATA, level=0)
+    assert len(fast) > len(DATA)
+    assert bytes(rust_demo.gzip_decompress(fast)) == DATA
+
+
+def test_gzip_errors():
+    with pytest.raises(ValueError, match="Invalid gzip data"):
+        rust_demo.gzip_decompress(b"not gzip at all")
+    with pytest.raises(ValueError, match="between 0 and 9, got 10"):
+        rust_demo.gzip_compress(DATA, level=10)
+
+
+def test_deflate_round_trip():
+    compressed = rust_demo.deflate_compress(DATA)
+    assert len(compressed) < len(DATA)
+    assert bytes(rust_demo.deflate_decompress(compressed)) == DATA
+    # Raw deflate stream, i.e. zlib with negative window bits
+    assert zlib.decompress(bytes(compressed), -15) == DATA
+
+
+def test_deflate_rejects_malformed_input():
+    with pytest.raises(ValueError, match="Invalid deflate data"):
+        rust_demo.deflate_decompress(b"\xff\xff\xff\xff")
+
+
+# byte_entropy
+
+
+def test_byte_entropy_extremes():
+    assert rust_demo.byte_entropy(b"\x00" * 1000) == pytest.approx(0.0, abs=1e-12)
+    assert rust_demo.byte_entropy(bytes(range(256)) * 4) == pytest.approx(8.0)
+    assert rust_demo.byte_entropy(b"abab") == pytest.approx(1.0)
+    assert rust_demo.byte_entropy(b"") == 0.0
@@ -0,0 +1,105 @@
+"""Tests for the encodings, ciphers and similarity hashes."""
+
+import pytest
+import rust_demo
+
+
+# to_morse / from_morse
+
+
+def test_morse_round_trips():
+    assert rust_demo.to_morse("SOS") == "... --- ..."
+    assert rust_demo.from_morse("... --- ...") == "SOS"
+    code = rust_demo.to_morse("Hello World")
+    assert code == ".... . .-.. .-.. --- / .-- --- .-. .-.. -.."
+    assert rust_demo.from_morse(code) == "HELLO WORLD"
+
+
+def test_from_morse_rejects_unknown_symbol():
+    with pytest.raises(ValueError, match=r"Unknown Morse sequence '\.\.\.\.\.\.\.'"):
+        rust_demo.from_morse("... .......")
+
+
+# rot47
+
+
+def test_rot47_known_mapping():
+    assert rust_demo.rot47("Hello, World!") == "w6==@[ (@C=5P"
+    assert rust_demo.rot47("!~") == "PO"
+
+
+def test_rot47_is_an_involution():
+    text = "The Quick Brown Fox: 1234567890 ~!@#$%^&*() é"
+    assert rust_demo.rot47(rust_demo.rot47(text)) == text
+
+
+# vigenere_encrypt / vigenere_decrypt
+
+
+def test_vigenere_known_vector():
+    assert rust_demo.vigenere_encrypt("ATTACKATDAWN", "LEMON") == "LXFOPVEFRNHR"
+    assert rust_demo.vigenere_decrypt("LXFOPVEFRNHR", "LEMON") == "ATTACKATDAWN"
+
+
+def test_vigenere_round_trip_preserves_case_and_punctuation():
+    text = "Attack at dawn, 5 AM!"
+    encrypted = rust_demo.vigenere_encrypt(text, "lemon")
+    assert encrypted == "Lxfopv ef rnhr, 5 MA!"
+    assert rust_demo.vigenere_decrypt(encrypted, "lemon") == text
+
+
+@pytest.mark.parametrize("key", ["", "abc1", "clé"])
+def test_vigenere_rejects_bad_keys(key):
+    with pytest.raises(ValueError, match="non-empty string of ASCII letters"):
+        rust_demo.vigenere_encrypt("text", key)
+
+
+# xor_cipher
+
+
+def test_xor_cipher_round_trip():
+    data = b"attack at dawn"
+    encrypted = rust_demo.xor_cipher(data, b"key")
+    assert encrypted != data
+    assert bytes(rust_demo.xor_cipher(encrypted, b"key")) == data
+
+
+def test_xor_cipher_single_byte_key():
+    assert bytes(rust_demo.xor_cipher(b"\x00\x0f\xff", b"\xff")) == b"\xff\xf0\x00"
+
+
+def test_xor_cipher_rejects_empty_key():
+    with pytest.raises(ValueError, match="Key must not be empty"):
+        rust_demo.xor_cipher(b"data", b"")
+
+
+# hamming_distance_bytes / simhash
+
+
+def test_hamming_distance_bytes_known_value():
+    assert rust_demo.hamming_distance_bytes(b"this is a test", b"wokka wokka!!!") == 37
+    assert rust_demo.hamming_distance_bytes(b"", b"") == 0
+
+
+def test_hamming_distance_bytes_rejects_length_mismatch():
+    with pytest.raises(rust_demo.DimensionMismatch, match="3 vs 2 bytes"):
+        rust_demo.hamming_distance_bytes(b"abc", b"ab")
+
+
+def test_simhash_near_duplicates_are_close():
+    text = (
+        "the quick brown fox jumps over the lazy dog while the cat "
+        "sleeps on a warm sunny windowsill all afternoon long"
+    ).split()
+    edited = list(text)
+    edited[3] = "wolf"
+    unrelated = (
+        "lorem ipsum dolor sit amet consectetur adipiscing elit sed do "
+        "eiusmod tempor incididunt ut labore et dolore magna aliqua"
+    ).split()
+
+    base = rust_demo.simhash(text)
+    near = rust_demo.hamming_distance_u64(base, rust_demo.simhash(edited))
+    far = rust_demo.hamming_distance_u64(base, rust_demo.simhash(unrelated))
+    assert near <= 8
+    assert far > 3 * near
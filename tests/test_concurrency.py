@@ -0,0 +1,123 @@
+"""Tests for the concurrency and instrumentation helpers."""
+
+import logging
+import math
+import threading
+
+import pytest
+import rust_demo
+
+
+# AtomicCounter
+
+
+def test_atomic_counter_unique_ids_across_threads():
+    counter = rust_demo.AtomicCounter()
+    num_threads, per_thread = 8, 5000
+    results = [[] for _ in range(num_threads)]
+
+    def worker(out):
+        for _ in range(per_thread):
+            out.append(counter.next())
+
+    threads = [threading.Thread(target=worker, args=(out,)) for out in results]
+    for thread in threads:
+        thread.start()
+    for thread in threads:
+        thread.join()
+
+    ids = [i for out in results for i in out]
+    total = num_threads * per_thread
+    assert len(set(ids)) == total
+    assert counter.get() == total
+
+
+def test_atomic_counter_start_and_reset():
+    counter = rust_demo.AtomicCounter(10)
+    assert counter.next() == 10
+    assert counter.get() == 11
+    counter.reset()
+    assert counter.get() == 0
+
+
+# time_call
+
+
+def test_time_call_trivial_lambda():
+    seconds = rust_demo.time_call(lambda: None, (), 100)
+    assert 0 < seconds < math.inf
+
+
+def test_time_call_passes_args_and_rejects_zero_iterations():
+    calls = []
+    rust_demo.time_call(calls.append, (1,), 3)
+    assert calls == [1, 1, 1]
+    with pytest.raises(ValueError, match="Iterations must be positive"):
+        rust_demo.time_call(lambda: None, (), 0)
+
+
+# init_logging
+
+
+class _Capture(logging.Handler):
+    def __init__(self):
+        super().__init__(level=logging.DEBUG)
+        self.records = []
+
+    def emit(self, record):
+        self.records.append(record)
+
+
+def _debug_messages(handler):
+    return [r.getMessage() for r in handler.records if r.levelno == logging.DEBUG]
+
+
+def test_init_logging_routes_debug_records():
+    logger = logging.getLogger("rust_demo")
+    handler = _Capture()
+    old_level = logger.level
+    logger.addHandler(handler)
+    try:
+        logger.setLevel(logging.DEBUG)
+        rust_demo.init_logging()
+        rust_demo.prime_sieve(1000)
+        messages = _debug_messages(handler)
+        assert any(m.startswith("prime_sieve: 168 primes") for m in messages)
+
+        # Calling again resets pyo3-log's cached levels to the new configuration
+        logger.setLevel(logging.WARNING)
+        rust_demo.init_logging()
+        handler.records.clear()
+        rust_demo.prime_sieve(1000)
+        assert _debug_messages(handler) == []
+    finally:
+        logger.removeHandler(handler)
+        logger.setLevel(old_level)
+
+
+# ParallelSession
+
+
+def test_parallel_session_matches_global_pool_results():
+    items = list(range(-500, 10_000, 7))
+    shuffled = items[::-1]
+    with rust_demo.ParallelSession(num_threads=2) as session:
+        assert session.num_threads == 2
+        assert session.sum(items) == rust_demo.parallel_sum(items) == sum(items)
+        assert session.sort(shuffled) == sorted(shuffled)
+
+
+def test_parallel_session_closed_after_exit():
+    with rust_demo.ParallelSession() as session:
+        pass
+    assert session.num_threads == 0
+    with pytest.raises(RuntimeError, match="ParallelSession is closed"):
+        session.sum([1, 2, 3])
+    with pytest.raises(RuntimeError, match="ParallelSession is closed"):
+        with session:
+            pass
+
+
+def test_parallel_session_rejects_zero_threads():
+    with pytest.raises(ValueError, match="Number of threads must be positive"):
+        rust_demo.ParallelSession(num_threads=0)
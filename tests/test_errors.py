@@ -0,0 +1,107 @@
+"""Tests for error handling and the custom exception hierarchy."""
+
+import math
+
+import pytest
+import rust_demo
+
+
+# safe_divide
+
+
+def test_safe_divide():
+    assert rust_demo.safe_divide(7.0, 2.0) == 3.5
+
+
+def test_safe_divide_by_zero_raises():
+    with pytest.raises(ZeroDivisionError, match="Division by zero"):
+        rust_demo.safe_divide(1.0, 0.0)
+
+
+def test_safe_divide_overflow_gives_infinity():
+    assert rust_demo.safe_divide(1e308, 1e-308) == math.inf
+
+
+# divide_lists
+
+
+def test_divide_lists_error_mode_names_the_index():
+    assert rust_demo.divide_lists([6.0, 9.0], [3.0, 3.0]) == [2.0, 3.0]
+    with pytest.raises(ZeroDivisionError, match="Division by zero at index 1"):
+        rust_demo.divide_lists([1.0, 2.0, 3.0], [1.0, 0.0, 0.0], on_zero="error")
+
+
+def test_divide_lists_nan_mode():
+    result = rust_demo.divide_lists([1.0, 4.0], [0.0, 2.0], on_zero="nan")
+    assert math.isnan(result[0])
+    assert result[1] == 2.0
+
+
+def test_divide_lists_inf_mode_follows_ieee():
+    result = rust_demo.divide_lists([1.0, -1.0, 0.0], [0.0, 0.0, 0.0], on_zero="inf")
+    assert result[:2] == [math.inf, -math.inf]
+    assert math.isnan(result[2])
+
+
+def test_divide_lists_zero_mode():
+    assert rust_demo.divide_lists([1.0, 4.0], [0.0, 2.0], on_zero="zero") == [0.0, 2.0]
+
+
+def test_divide_lists_rejects_unknown_mode_and_length_mismatch():
+    with pytest.raises(ValueError, match="Unknown on_zero mode 'skip'"):
+        rust_demo.divide_lists([1.0], [1.0], on_zero="skip")
+    with pytest.raises(rust_demo.DimensionMismatch, match="Length mismatch: 2 vs 1"):
+        rust_demo.divide_lists([1.0, 2.0], [1.0])
+
+
+# Custom exception hierarchy
+
+
+def test_exception_hierarchy():
+    assert issubclass(rust_demo.RustDemoError, ValueError)
+    assert issubclass(rust_demo.DimensionMismatch, rust_demo.RustDemoError)
+    assert issubclass(rust_demo.DimensionMismatch, ValueError)
+    assert issubclass(rust_demo.EmptyInput, rust_demo.RustDemoError)
+
+
+def test_dimension_mismatch_is_catchable():
+    try:
+        rust_demo.matrix_multiply([1.0, 2.0, 3.0], [1.0, 2.0], 2, 2, 1)
+    except rust_demo.DimensionMismatch as exc:
+        assert "Matrix A size mismatch" in str(exc)
+    else:
+        pytest.fail("DimensionMismatch not raised")
+
+    # Existing `except ValueError` callers keep working
+    with pytest.raises(ValueError):
+        rust_demo.matrix_multiply([1.0, 2.0, 3.0], [1.0, 2.0], 2, 2, 1)
+
+
+def test_empty_input_is_catchable():
+    with pytest.raises(rust_demo.EmptyInput):
+        rust_demo.mode_ints([])
+    with pytest.raises(rust_demo.RustDemoError):
+        rust_demo.mode_ints([])
+
+
+def test_size_mismatch_attributes_for_matrix_a():
+    with pytest.raises(rust_demo.DimensionMismatch) as exc_info:
+        rust_demo.matrix_multiply([1.0, 2.0, 3.0], [1.0, 2.0], 2, 2, 1)
+    assert "Matrix A" in str(exc_info.value)
+    assert exc_info.value.expected == 4
+    assert exc_info.value.actual == 3
+
+
+def test_size_mismatch_attributes_for_matrix_b():
+    with pytest.raises(rust_demo.DimensionMismatch) as exc_info:
+        rust_demo.matrix_multiply([1.0, 2.0, 3.0, 4.0], [1.0], 2, 2, 1)
+    assert "Matrix B" in str(exc_info.value)
+    assert exc_info.value.expected == 2
+    assert exc_info.value.actual == 1
+
+
+def test_size_mismatch_attributes_for_matrix_power():
+    with pytest.raises(rust_demo.DimensionMismatch) as exc_info:
+        rust_demo.matrix_power([1.0, 2.0, 3.0], 2, 3)
+    assert exc_info.value.expected == 4
+    assert exc_info.value.actual == 3
@@ -0,0 +1,60 @@
+"""Tests for the graph algorithms."""
+
+import math
+
+import pytest
+import rust_demo
+
+
+# dijkstra
+
+
+def test_dijkstra_known_graph():
+    edges = [
+        (0, 1, 4.0),
+        (0, 2, 1.0),
+        (2, 1, 2.0),
+        (1, 3, 1.0),
+        (2, 3, 5.0),
+    ]
+    assert rust_demo.dijkstra(5, edges, 0) == [0.0, 3.0, 1.0, 4.0, math.inf]
+
+
+def test_dijkstra_rejects_negative_weight():
+    with pytest.raises(ValueError, match="weights must be non-negative"):
+        rust_demo.dijkstra(2, [(0, 1, -1.0)], 0)
+
+
+def test_dijkstra_rejects_out_of_range_nodes():
+    with pytest.raises(ValueError, match="Node 3 out of range for graph with 3 nodes"):
+        rust_demo.dijkstra(3, [(0, 3, 1.0)], 0)
+    with pytest.raises(ValueError, match="out of range"):
+        rust_demo.dijkstra(3, [], 5)
+
+
+# topological_sort
+
+
+def test_topological_sort_respects_every_edge():
+    edges = [(5, 2), (5, 0), (4, 0), (4, 1), (2, 3), (3, 1)]
+    order = rust_demo.topological_sort(6, edges)
+    assert sorted(order) == list(range(6))
+    position = {node: i for i, node in enumerate(order)}
+    assert all(position[a] < position[b] for a, b in edges)
+
+
+def test_topological_sort_rejects_cycle():
+    with pytest.raises(ValueError, match="Graph contains a cycle"):
+        rust_demo.topological_sort(3, [(0, 1), (1, 2), (2, 0)])
+
+
+# connected_components
+
+
+def test_connected_components_two_clusters_and_isolated_node():
+    edges = [(0, 1), (1, 2), (3, 4)]
+    assert rust_demo.connected_components(6, edges) == [0, 0, 0, 1, 1, 2]
+
+
+def test_connected_components_labels_follow_lowest_node():
+    assert rust_demo.connected_components(4, [(3, 1), (2, 0)]) == [0, 1, 0, 1]
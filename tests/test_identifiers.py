@@ -0,0 +1,82 @@
+"""Tests for the identifier generators."""
+
+import re
+import string
+import time
+import uuid
+
+import pytest
+import rust_demo
+
+UUID4_RE = re.compile(
+    r"^[0-9a-f]{8}-[0-9a-f]{4}-4[0-9a-f]{3}-[89ab][0-9a-f]{3}-[0-9a-f]{12}$"
+)
+
+
+# uuid4 / uuid4_batch
+
+
+def test_uuid4_format():
+    value = rust_demo.uuid4()
+    assert UUID4_RE.match(value)
+    assert uuid.UUID(value).version == 4
+
+
+def test_uuid4_batch_is_distinct():
+    batch = rust_demo.uuid4_batch(1000)
+    assert len(batch) == 1000
+    assert len(set(batch)) == 1000
+    assert all(UUID4_RE.match(value) for value in batch)
+
+
+# uuid5
+
+
+def test_uuid5_matches_python_uuid_module():
+    namespace = str(uuid.NAMESPACE_DNS)
+    value = rust_demo.uuid5(namespace, "python.org")
+    assert value == str(uuid.uuid5(uuid.NAMESPACE_DNS, "python.org"))
+    assert rust_demo.uuid5(namespace, "python.org") == value
+    assert rust_demo.uuid5(namespace, "rust-lang.org") != value
+
+
+def test_uuid5_rejects_bad_namespace():
+    with pytest.raises(ValueError, match="Invalid namespace UUID 'not-a-uuid'"):
+        rust_demo.uuid5("not-a-uuid", "name")
+
+
+# ulid / nanoid
+
+CROCKFORD = "0123456789ABCDEFGHJKMNPQRSTVWXYZ"
+
+
+def test_ulid_format_and_timestamp():
+    before = int(time.time() * 1000)
+    value = rust_demo.ulid()
+    after = int(time.time() * 1000)
+    assert len(value) == 26
+    assert set(value) <= set(CROCKFORD)
+    # The first 10 characters encode the 48-bit millisecond timestamp
+    millis = 0
+    for c in value[:10]:
+        millis = millis * 32 + CROCKFORD.index(c)
+    assert before <= millis <= after
+
+
+def test_ulid_sorts_by_creation_time():
+    ids = []
+    for _ in range(5):
+        ids.append(rust_demo.ulid())
+        time.sleep(0.002)
+    assert ids == sorted(ids)
+    assert len(set(ids)) == 5
+
+
+def test_nanoid_length_and_alphabet():
+    alphabet = set(string.ascii_letters + string.digits + "_-")
+    assert len(rust_demo.nanoid()) == 21
+    assert len(rust_demo.nanoid(8)) == 8
+    assert rust_demo.nanoid(0) == ""
+    ids = [rust_demo.nanoid() for _ in range(200)]
+    assert all(set(value) <= alphabet for value in ids)
+    assert len(set(ids)) == 200
@@ -0,0 +1,129 @@
+"""Tests for the number-theory functions."""
+
+import pytest
+import rust_demo
+
+
+# goldbach
+
+
+def test_goldbach_smallest_partition():
+    assert rust_demo.goldbach(10) == (3, 7)
+    assert rust_demo.goldbach(4) == (2, 2)
+    assert rust_demo.goldbach(100) == (3, 97)
+
+
+@pytest.mark.parametrize("n", [2, 9, 11])
+def test_goldbach_rejects_small_or_odd_input(n):
+    with pytest.raises(ValueError, match=f"even number >= 4, got {n}"):
+        rust_demo.goldbach(n)
+
+
+# twin_primes
+
+
+def test_twin_primes_up_to_20():
+    assert rust_demo.twin_primes(20) == [(3, 5), (5, 7), (11, 13), (17, 19)]
+    assert rust_demo.twin_primes(4) == []
+
+
+# totient
+
+
+def test_totient():
+    assert rust_demo.totient(9) == 6
+    assert rust_demo.totient(1) == 1
+    assert rust_demo.totient(97) == 96
+    assert rust_demo.totient(36) == 12
+
+
+def test_totient_of_semiprimes_past_trial_division():
+    # Factors above the trial-division bound must come from Pollard rho
+    cases = [
+        (1_009, 1_013),
+        (1_000_003, 1_000_033),
+        (4_294_967_279, 4_294_967_291),
+    ]
+    for p, q in cases:
+        assert rust_demo.totient(p * q) == (p - 1) * (q - 1)
+    assert rust_demo.totient(2 * 1_009 * 1_009) == 1_009 * 1_008
+
+
+# divisors / divisor_sum / classify_number
+
+
+def test_divisors_and_divisor_sum():
+    assert rust_demo.divisors(12) == [1, 2, 3, 4, 6, 12]
+    assert rust_demo.divisor_sum(12) == 28
+    assert rust_demo.divisors(1) == [1]
+    assert rust_demo.divisor_sum(1) == 1
+
+
+def test_divisors_reject_zero():
+    with pytest.raises(ValueError, match="n must be positive"):
+        rust_demo.divisors(0)
+    with pytest.raises(ValueError, match="n must be positive"):
+        rust_demo.divisor_sum(0)
+
+
+def test_classify_number():
+    assert rust_demo.classify_number(6) == "perfect"
+    assert rust_demo.classify_number(12) == "abundant"
+    assert rust_demo.classify_number(8) == "deficient"
+    assert rust_demo.classify_number(28) == "perfect"
+    assert rust_demo.classify_number(1) == "deficient"
+
+
+# continued_fraction / reduce_fraction
+
+
+def test_continued_fraction():
+    assert rust_demo.continued_fraction(415, 93) == [4, 2, 6, 7]
+    assert rust_demo.continued_fraction(6, 3) == [2]
+
+
+def test_continued_fraction_negative_uses_floor():
+    # -415/93 = -5 + 1/(1 + 1/(1 + 1/(6 + 1/7)))
+    assert rust_demo.continued_fraction(-415, 93) == [-5, 1, 1, 6, 7]
+    assert rust_demo.continued_fraction(415, -93) == [-5, 1, 1, 6, 7]
+
+
+def test_continued_fraction_rejects_zero_denominator():
+    with pytest.raises(ZeroDivisionError, match="Denominator must be non-zero"):
+        rust_demo.continued_fraction(1, 0)
+
+
+def test_reduce_fraction():
+    assert rust_demo.reduce_fraction(6, -8) == (-3, 4)
+    assert rust_demo.reduce_fraction(0, 5) == (0, 1)
+    assert rust_demo.reduce_fraction(-10, -4) == (5, 2)
+
+
+def test_reduce_fraction_rejects_zero_denominator():
+    with pytest.raises(ZeroDivisionError, match="Denominator must be non-zero"):
+        rust_demo.reduce_fraction(3, 0)
+
+
+# binomial / catalan
+
+
+def test_binomial():
+    assert rust_demo.binomial(5, 2) == 10
+    assert rust_demo.binomial(10, 0) == 1
+    assert rust_demo.binomial(3, 5) == 0
+    assert rust_demo.binomial(67, 33) == 14226520737620288370
+
+
+def test_binomial_overflow():
+    with pytest.raises(OverflowError, match=r"C\(100, 50\) exceeds u64"):
+        rust_demo.binomial(100, 50)
+
+
+def test_catalan_sequence():
+    assert [rust_demo.catalan(n) for n in range(6)] == [1, 1, 2, 5, 14, 42]
+    assert rust_demo.catalan(36) == 11959798385860453492
+
+
+def test_catalan_overflow():
+    with pytest.raises(OverflowError, match="Catalan number 37 exceeds u64"):
+        rust_demo.catalan(37)
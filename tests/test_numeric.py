@@ -0,0 +1,147 @@
+"""Tests for the numeric and matrix functions."""
+
+import itertools
+import math
+
+import pytest
+import rust_demo
+
+
+# matrix_power
+
+
+def test_matrix_power_squares_a_2x2_matrix():
+    assert rust_demo.matrix_power([1.0, 2.0, 3.0, 4.0], 2, 2) == [7.0, 10.0, 15.0, 22.0]
+
+
+def test_matrix_power_fibonacci():
+    # [[1, 1], [1, 0]]^10 == [[F11, F10], [F10, F9]]
+    result = rust_demo.matrix_power([1.0, 1.0, 1.0, 0.0], 2, 10)
+    assert result == [89.0, 55.0, 55.0, 34.0]
+
+
+def test_matrix_power_zero_exponent_is_identity():
+    assert rust_demo.matrix_power([5.0, 6.0, 7.0, 8.0], 2, 0) == [1.0, 0.0, 0.0, 1.0]
+
+
+def test_matrix_power_rejects_overflowing_dimension():
+    with pytest.raises(OverflowError):
+        rust_demo.matrix_power([], 2**32, 1)
+
+
+# parallel_cumsum
+
+
+def test_parallel_cumsum_matches_sequential_across_blocks():
+    # Well past the 1024-element minimum block size, so several blocks are used;
+    # small integers keep every partial sum exact
+    items = [float(i % 17 - 8) for i in range(100_003)]
+    assert rust_demo.parallel_cumsum(items) == list(itertools.accumulate(items))
+
+
+def test_parallel_cumsum_small_and_empty():
+    assert rust_demo.parallel_cumsum([1.0, 2.0, 3.0]) == [1.0, 3.0, 6.0]
+    assert rust_demo.parallel_cumsum([]) == []
+
+
+# bit_reverse
+
+
+def test_bit_reverse():
+    assert rust_demo.bit_reverse(0b001, 3) == 0b100
+    assert rust_demo.bit_reverse(0b1101, 4) == 0b1011
+    assert rust_demo.bit_reverse(1, 64) == 1 << 63
+    assert rust_demo.bit_reverse(0, 0) == 0
+
+
+def test_bit_reverse_rejects_out_of_range():
+    with pytest.raises(ValueError, match="Value 8 does not fit in 3 bits"):
+        rust_demo.bit_reverse(8, 3)
+    with pytest.raises(ValueError, match="bits must be at most 64, got 65"):
+        rust_demo.bit_reverse(1, 65)
+
+
+# fft
+
+
+def test_fft_cosine_peaks_at_bins_one_and_n_minus_one():
+    n = 16
+    signal = [math.cos(2 * math.pi * k / n) for k in range(n)]
+    real, imag = rust_demo.fft(signal, [0.0] * n)
+    magnitudes = [math.hypot(re, im) for re, im in zip(real, imag)]
+    assert magnitudes[1] == pytest.approx(n / 2)
+    assert magnitudes[n - 1] == pytest.approx(n / 2)
+    others = magnitudes[2 : n - 1] + magnitudes[:1]
+    assert max(others) == pytest.approx(0.0, abs=1e-9)
+
+
+def test_fft_inverse_round_trip_via_conjugate():
+    # No inverse is exposed: ifft(X) == conj(fft(conj(X))) / n
+    real = [1.0, 2.0, 0.0, -1.0, 3.5, 0.25, -2.0, 4.0]
+    imag = [0.0, -1.0, 2.0, 0.5, 0.0, 1.0, -3.0, 0.0]
+    n = len(real)
+    spec_re, spec_im = rust_demo.fft(real, imag)
+    back_re, back_im = rust_demo.fft(spec_re, [-v for v in spec_im])
+    assert [v / n for v in back_re] == pytest.approx(real, abs=1e-12)
+    assert [-v / n for v in back_im] == pytest.approx(imag, abs=1e-12)
+
+
+def test_fft_rejects_bad_lengths():
+    with pytest.raises(ValueError, match="Length must be a power of two, got 6"):
+        rust_demo.fft([0.0] * 6, [0.0] * 6)
+    with pytest.raises(rust_demo.DimensionMismatch, match="Length mismatch"):
+        rust_demo.fft([0.0] * 4, [0.0] * 2)
+
+
+# poly_eval / poly_root
+
+
+def test_poly_eval():
+    assert rust_demo.poly_eval([1.0, 2.0, 1.0], 3.0) == 16.0
+    assert rust_demo.poly_eval([], 3.0) == 0.0
+
+
+def test_poly_root_converges_to_sqrt2():
+    root = rust_demo.poly_root([1.0, 0.0, -2.0], 1.0, 1e-12, 50)
+    assert root == pytest.approx(math.sqrt(2), abs=1e-12)
+
+
+def test_poly_root_errors():
+    with pytest.raises(ValueError, match="Zero derivative at x = 0"):
+        rust_demo.poly_root([1.0, 0.0, -2.0], 0.0, 1e-12, 50)
+    # x² + 1 has no real root, so Newton's method wanders forever
+    with pytest.raises(ValueError, match="Failed to converge within 50 iterations"):
+        rust_demo.poly_root([1.0, 0.0, 1.0], 0.5, 1e-12, 50)
+    with pytest.raises(ValueError, match="Tolerance must be positive"):
+        rust_demo.poly_root([1.0, 0.0, -2.0], 1.0, 0.0, 50)
+
+
+# simpson / trapezoid
+
+
+def test_simpson_integrates_sin_over_0_pi():
+    n = 101
+    dx = math.pi / (n - 1)
+    y = [math.sin(i * dx) for i in range(n)]
+    assert rust_demo.simpson(y, dx) == pytest.approx(2.0, abs=1e-7)
+
+
+def test_simpson_rejects_even_point_count():
+    with pytest.raises(ValueError, match="odd number of points >= 3, got 4"):
+        rust_demo.simpson([0.0, 1.0, 2.0, 3.0], 1.0)
+
+
+def test_trapezoid_uniform_spacing():
+    assert rust_demo.trapezoid([1.0, 2.0, 3.0]) == 4.0
+    assert rust_demo.trapezoid([1.0, 2.0, 3.0], dx=0.5) == 2.0
+    assert rust_demo.trapezoid([5.0]) == 0.0
+
+
+def test_trapezoid_non_uniform_points():
+    # (1 - 0) * (0 + 1) / 2 + (3 - 1) * (1 + 3) / 2 = 0.5 + 4
+    assert rust_demo.trapezoid([0.0, 1.0, 3.0], x=[0.0, 1.0, 3.0]) == 4.5
+
+
+def test_trapezoid_rejects_x_and_dx_together():
+    with pytest.raises(ValueError, match="Pass either x or dx, not both"):
+        rust_demo.trapezoid([1.0, 2.0], x=[0.0, 1.0], dx=1.0)
@@ -0,0 +1,95 @@
+"""Tests for the NumPy interop functions (skipped when NumPy is not installed)."""
+
+import pytest
+import rust_demo
+
+np = pytest.importorskip("numpy")
+
+
+# sum_numpy
+
+
+def test_sum_numpy_matches_ndarray_sum():
+    arr = np.linspace(-3.0, 7.0, 10_001)
+    assert rust_demo.sum_numpy(arr) == pytest.approx(arr.sum())
+
+
+def test_sum_numpy_empty_array():
+    assert rust_demo.sum_numpy(np.array([], dtype=np.float64)) == 0.0
+
+
+# normalize_inplace
+
+
+def test_normalize_inplace_gives_unit_norm():
+    arr = np.array([3.0, 4.0])
+    assert rust_demo.normalize_inplace(arr) is None
+    np.testing.assert_allclose(arr, [0.6, 0.8])
+    assert np.linalg.norm(arr) == pytest.approx(1.0)
+
+
+def test_normalize_inplace_rejects_zero_vector():
+    with pytest.raises(ValueError, match="Cannot normalize a zero vector"):
+        rust_demo.normalize_inplace(np.zeros(3))
+
+
+# apply_ufunc
+
+
+@pytest.mark.parametrize("op", ["sqrt", "exp", "log", "sin", "cos", "abs"])
+def test_apply_ufunc_matches_numpy(op):
+    arr = np.linspace(0.1, 5.0, 50)
+    np.testing.assert_allclose(rust_demo.apply_ufunc(arr, op), getattr(np, op)(arr))
+
+
+def test_apply_ufunc_rejects_unknown_op():
+    with pytest.raises(ValueError, match="Unknown op 'tan'"):
+        rust_demo.apply_ufunc(np.ones(3), "tan")
+
+
+def test_apply_ufunc_domain_errors_give_nan():
+    result = rust_demo.apply_ufunc(np.array([-1.0, 4.0]), "sqrt")
+    assert np.isnan(result[0])
+    assert result[1] == 2.0
+    assert np.isnan(rust_demo.apply_ufunc(np.array([-1.0]), "log")[0])
+
+
+# moving_average_np
+
+
+def test_moving_average_np_matches_convolve_valid():
+    arr = np.random.default_rng(0).normal(size=200)
+    for window in (1, 3, 50, 200):
+        expected = np.convolve(arr, np.ones(window) / window, "valid")
+        np.testing.assert_allclose(rust_demo.moving_average_np(arr, window), expected)
+
+
+def test_moving_average_np_rejects_bad_window():
+    arr = np.arange(5, dtype=np.float64)
+    with pytest.raises(ValueError, match="got 0"):
+        rust_demo.moving_average_np(arr, 0)
+    with pytest.raises(ValueError, match="got 6"):
+        rust_demo.moving_average_np(arr, 6)
+
+
+# pairwise_distances
+
+
+def test_pairwise_distances_matches_broadcast_reference():
+    points = np.random.default_rng(1).normal(size=(30, 4))
+    # Pure-NumPy equivalent of scipy.spatial.distance.cdist(points, points)
+    expected = np.sqrt(((points[:, None, :] - points[None, :, :]) ** 2).sum(axis=-1))
+    result = rust_demo.pairwise_distances(points)
+    assert result.shape == (30, 30)
+    np.testing.assert_allclose(result, expected, atol=1e-12)
+    np.testing.assert_array_equal(np.diag(result), 0.0)
+
+
+# prime_sieve_np
+
+
+@pytest.mark.parametrize("n", [0, 1, 2, 3, 100, 128, 129, 100_000])
+def test_prime_sieve_np_matches_prime_sieve(n):
+    primes = rust_demo.prime_sieve_np(n)
+    assert primes.dtype == np.uint64
+    assert primes.tolist() == rust_demo.prime_sieve(n)
@@ -0,0 +1,232 @@
+"""Tests for the statistics and similarity functions."""
+
+import pytest
+import rust_demo
+
+
+# mode_ints
+
+
+def test_mode_ints_single_and_tied_modes():
+    assert rust_demo.mode_ints([1, 2, 2, 3]) == [2]
+    assert rust_demo.mode_ints([2, 2, 1, 1]) == [1, 2]
+
+
+def test_mode_ints_rejects_empty_list():
+    with pytest.raises(rust_demo.EmptyInput, match="mode of an empty list"):
+        rust_demo.mode_ints([])
+
+
+# weighted_mean
+
+
+def test_weighted_mean_hand_computed():
+    # (1*1 + 2*2 + 3*3) / (1 + 2 + 3) = 14 / 6
+    result = rust_demo.weighted_mean([1.0, 2.0, 3.0], [1.0, 2.0, 3.0])
+    assert result == pytest.approx(14 / 6)
+
+
+def test_weighted_mean_rejects_zero_total_weight():
+    with pytest.raises(ValueError, match="Total weight must be non-zero"):
+        rust_demo.weighted_mean([1.0, 2.0], [1.0, -1.0])
+
+
+# linear_regression / pearson_correlation
+
+
+def test_linear_regression_perfect_line():
+    x = [0.0, 1.0, 2.0, 3.0]
+    y = [1.0, 3.0, 5.0, 7.0]
+    slope, intercept, r_squared = rust_demo.linear_regression(x, y)
+    assert slope == pytest.approx(2.0)
+    assert intercept == pytest.approx(1.0)
+    assert r_squared == pytest.approx(1.0)
+
+
+def test_linear_regression_noisy_data():
+    # Reference values from statistics.linear_regression and correlation()**2
+    x = [1.0, 2.0, 3.0, 4.0, 5.0]
+    y = [2.1, 3.9, 6.2, 7.8, 10.1]
+    slope, intercept, r_squared = rust_demo.linear_regression(x, y)
+    assert slope == pytest.approx(1.99)
+    assert intercept == pytest.approx(0.05)
+    assert r_squared == pytest.approx(0.9973053289009772)
+
+
+def test_linear_regression_rejects_constant_x():
+    with pytest.raises(ValueError, match="x values must not all be equal"):
+        rust_demo.linear_regression([2.0, 2.0], [1.0, 3.0])
+
+
+def test_pearson_correlation():
+    x = [1.0, 2.0, 3.0, 4.0, 5.0]
+    r = rust_demo.pearson_correlation
+    assert r(x, [2.0, 4.0, 6.0, 8.0, 10.0]) == pytest.approx(1.0)
+    assert r(x, [5.0, 4.0, 3.0, 2.0, 1.0]) == pytest.approx(-1.0)
+    assert r(x, [2.0, 1.0, 4.0, 3.0, 5.0]) == pytest.approx(0.8)
+    assert r(x, [1.0, -1.0, 0.0, -1.0, 1.0]) == pytest.approx(0.0, abs=1e-12)
+
+
+def test_pearson_correlation_rejects_constant_input():
+    with pytest.raises(ValueError, match="Standard deviation"):
+        rust_demo.pearson_correlation([1.0, 2.0, 3.0], [4.0, 4.0, 4.0])
+
+
+# minmax_scale / zscore / clamp_list
+
+
+def test_minmax_scale():
+    scaled = rust_demo.minmax_scale([1.0, 2.0, 3.0, 4.0, 5.0])
+    assert scaled == [0.0, 0.25, 0.5, 0.75, 1.0]
+
+
+def test_minmax_scale_constant_input_maps_to_zeros():
+    assert rust_demo.minmax_scale([3.0, 3.0, 3.0]) == [0.0, 0.0, 0.0]
+
+
+def test_minmax_scale_rejects_empty_list():
+    with pytest.raises(rust_demo.EmptyInput):
+        rust_demo.minmax_scale([])
+
+
+def test_zscore_has_zero_mean_and_unit_sample_std():
+    scores = rust_demo.zscore([2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0])
+    mean = sum(scores) / len(scores)
+    variance = sum((z - mean) ** 2 for z in scores) / (len(scores) - 1)
+    assert mean == pytest.approx(0.0, abs=1e-12)
+    assert variance == pytest.approx(1.0)
+
+
+def test_zscore_rejects_short_and_constant_input():
+    with pytest.raises(rust_demo.EmptyInput, match="Need at least 2 values, got 1"):
+        rust_demo.zscore([1.0])
+    with pytest.raises(ValueError, match="Variance must be non-zero"):
+        rust_demo.zscore([2.0, 2.0])
+
+
+def test_clamp_list():
+    assert rust_demo.clamp_list([-1.0, 0.5, 2.0], 0.0, 1.0) == [0.0, 0.5, 1.0]
+
+
+def test_clamp_list_rejects_bad_bounds():
+    with pytest.raises(ValueError, match=r"low \(2\) must not exceed high \(1\)"):
+        rust_demo.clamp_list([1.0], 2.0, 1.0)
+    with pytest.raises(ValueError, match="Bounds must not be NaN"):
+        rust_demo.clamp_list([1.0], float("nan"), 1.0)
+
+
+# argtop_k / argsort / rank / spearman_correlation
+
+
+def test_argtop_k():
+    assert rust_demo.argtop_k([3.0, 1.0, 4.0, 1.0, 5.0], 2) == [4, 2]
+
+
+def test_argtop_k_ties_prefer_smaller_index_and_k_exceeds_len():
+    assert rust_demo.argtop_k([2.0, 7.0, 7.0, 1.0], 2) == [1, 2]
+    assert rust_demo.argtop_k([1.0, 3.0, 2.0], 10) == [1, 2, 0]
+    assert rust_demo.argtop_k([1.0, 3.0], 0) == []
+
+
+def test_argtop_k_rejects_nan():
+    with pytest.raises(ValueError, match=r"Cannot rank NaN \(at index 1\)"):
+        rust_demo.argtop_k([1.0, float("nan"), 2.0], 1)
+
+
+def test_argsort():
+    assert rust_demo.argsort([3.0, 1.0, 2.0]) == [1, 2, 0]
+
+
+def test_argsort_is_stable_on_ties():
+    assert rust_demo.argsort([2.0, 1.0, 2.0, 1.0]) == [1, 3, 0, 2]
+
+
+def test_argsort_rejects_nan():
+    with pytest.raises(ValueError, match=r"Cannot sort NaN \(at index 2\)"):
+        rust_demo.argsort([1.0, 2.0, float("nan")])
+
+
+def test_rank_averages_ties():
+    assert rust_demo.rank([10.0, 20.0, 20.0, 30.0]) == [1.0, 2.5, 2.5, 4.0]
+    assert rust_demo.rank([3.0, 1.0, 2.0]) == [3.0, 1.0, 2.0]
+    assert rust_demo.rank([]) == []
+
+
+def test_spearman_correlation_known_dataset():
+    # scipy.stats.spearmanr([1, 2, 3, 4, 5], [5, 6, 7, 8, 7])
+    x = [1.0, 2.0, 3.0, 4.0, 5.0]
+    y = [5.0, 6.0, 7.0, 8.0, 7.0]
+    assert rust_demo.spearman_correlation(x, y) == pytest.approx(0.8207826816681233)
+
+
+def test_spearman_correlation_monotonic_is_one():
+    x = [1.0, 2.0, 3.0, 4.0]
+    cubes = [1.0, 8.0, 27.0, 64.0]
+    assert rust_demo.spearman_correlation(x, cubes) == pytest.approx(1.0)
+
+
+# shannon_entropy / gini_impurity
+
+
+def test_shannon_entropy():
+    assert rust_demo.shannon_entropy([5, 5, 5, 5]) == pytest.approx(2.0)
+    assert rust_demo.shannon_entropy([7]) == 0.0
+    assert rust_demo.shannon_entropy([3, 0, 3]) == pytest.approx(1.0)
+
+
+def test_shannon_entropy_rejects_zero_total():
+    with pytest.raises(ValueError, match="Total count must be non-zero"):
+        rust_demo.shannon_entropy([0, 0])
+    with pytest.raises(ValueError):
+        rust_demo.shannon_entropy([])
+
+
+def test_gini_impurity():
+    assert rust_demo.gini_impurity([10]) == 0.0
+    assert rust_demo.gini_impurity([5, 5]) == pytest.approx(0.5)
+    assert rust_demo.gini_impurity([1, 1, 1, 1]) == pytest.approx(0.75)
+
+
+def test_gini_impurity_rejects_zero_total():
+    with pytest.raises(ValueError, match="Total count must be non-zero"):
+        rust_demo.gini_impurity([0])
+
+
+# sparse_cosine / jaccard_similarity / estimate_jaccard
+
+
+def test_sparse_cosine_overlapping_keys():
+    a = {"x": 1.0, "y": 2.0}
+    b = {"y": 2.0, "z": 1.0}
+    # dot = 4, |a| = |b| = sqrt(5)
+    assert rust_demo.sparse_cosine(a, b) == pytest.approx(0.8)
+    assert rust_demo.sparse_cosine(a, a) == pytest.approx(1.0)
+
+
+def test_sparse_cosine_no_shared_keys_or_zero_norm():
+    assert rust_demo.sparse_cosine({"a": 1.0}, {"b": 1.0}) == 0.0
+    assert rust_demo.sparse_cosine({}, {"b": 1.0}) == 0.0
+
+
+def test_jaccard_similarity():
+    assert rust_demo.jaccard_similarity([1, 2, 3], [3, 2, 1]) == 1.0
+    assert rust_demo.jaccard_similarity([1, 2], [3, 4]) == 0.0
+    assert rust_demo.jaccard_similarity([1, 2, 3, 3], [2, 3, 4]) == 0.5
+    assert rust_demo.jaccard_similarity([], []) == 1.0
+
+
+def test_estimate_jaccard_close_to_exact():
+    a = list(range(0, 1000))
+    b = list(range(500, 1500))  # exact Jaccard = 500 / 1500
+    hasher = rust_demo.MinHasher(256)
+    estimate = rust_demo.estimate_jaccard(hasher.signature(a), hasher.signature(b))
+    assert estimate == pytest.approx(rust_demo.jaccard_similarity(a, b), abs=0.1)
+    sig = hasher.signature(a)
+    assert rust_demo.estimate_jaccard(sig, sig) == 1.0
+
+
+def test_estimate_jaccard_rejects_bad_signatures():
+    with pytest.raises(rust_demo.DimensionMismatch, match="Signature length mismatch"):
+        rust_demo.estimate_jaccard([1, 2], [1])
+    with pytest.raises(rust_demo.EmptyInput):
+        rust_demo.estimate_jaccard([], [])
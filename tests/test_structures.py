@@ -0,0 +1,348 @@
+"""Tests for the probabilistic and classic data-structure classes."""
+
+import random
+
+import pytest
+import rust_demo
+
+
+# BloomFilter
+
+
+def _bloom_with(n, rate):
+    bloom = rust_demo.BloomFilter(n, rate)
+    for i in range(n):
+        bloom.add(f"item-{i}".encode())
+    return bloom
+
+
+def test_bloom_filter_no_false_negatives():
+    bloom = _bloom_with(10_000, 0.01)
+    assert all(f"item-{i}".encode() in bloom for i in range(10_000))
+
+
+def test_bloom_filter_false_positive_rate_near_target():
+    bloom = _bloom_with(10_000, 0.01)
+    trials = 100_000
+    false_positives = sum(bloom.contains(f"other-{i}".encode()) for i in range(trials))
+    assert false_positives / trials == pytest.approx(0.01, abs=0.005)
+
+
+def test_bloom_filter_rejects_bad_parameters():
+    with pytest.raises(ValueError):
+        rust_demo.BloomFilter(0, 0.01)
+    with pytest.raises(ValueError):
+        rust_demo.BloomFilter(100, 1.5)
+
+
+# CountMinSketch
+
+
+def test_count_min_sketch_never_underestimates():
+    sketch = rust_demo.CountMinSketch(64, 4)
+    true_counts = {f"key-{i}".encode(): i % 7 + 1 for i in range(500)}
+    for key, count in true_counts.items():
+        sketch.add(key, count)
+    for key, count in true_counts.items():
+        assert sketch.estimate(key) >= count
+
+
+def test_count_min_sketch_exact_when_sparse():
+    sketch = rust_demo.CountMinSketch(1000, 5)
+    sketch.add(b"apple")
+    sketch.add(b"apple", 2)
+    assert sketch.estimate(b"apple") == 3
+    assert sketch.estimate(b"banana") == 0
+
+
+def test_count_min_sketch_rejects_oversized_table():
+    with pytest.raises(ValueError):
+        rust_demo.CountMinSketch(2**32, 2**32)
+
+
+# HyperLogLog
+
+
+def test_hyperloglog_estimates_a_million_distinct_items():
+    hll = rust_demo.HyperLogLog()
+    for i in range(1_000_000):
+        hll.add(f"user-{i}".encode())
+    assert hll.count() == pytest.approx(1_000_000, rel=0.03)
+
+
+def test_hyperloglog_ignores_duplicates():
+    hll = rust_demo.HyperLogLog()
+    for _ in range(10):
+        for i in range(1000):
+            hll.add(str(i).encode())
+    assert hll.count() == pytest.approx(1000, rel=0.03)
+
+
+def test_hyperloglog_merge_estimates_the_union():
+    a = rust_demo.HyperLogLog()
+    b = rust_demo.HyperLogLog()
+    for i in range(20_000):
+        a.add(f"x{i}".encode())
+    for i in range(10_000, 30_000):
+        b.add(f"x{i}".encode())
+    a.merge(b)
+    assert a.count() == pytest.approx(30_000, rel=0.03)
+
+
+def test_hyperloglog_merge_rejects_precision_mismatch():
+    with pytest.raises(ValueError, match="Precision mismatch: 14 vs 10"):
+        rust_demo.HyperLogLog(14).merge(rust_demo.HyperLogLog(10))
+
+
+# LruCache
+
+
+def test_lru_cache_evicts_least_recently_used():
+    cache = rust_demo.LruCache(2)
+    cache.put(1, 1)
+    cache.put(2, 2)
+    assert cache.get(1) == 1
+    cache.put(3, 3)  # evicts 2
+    assert cache.get(2) is None
+    cache.put(4, 4)  # evicts 1
+    assert cache.get(1) is None
+    assert cache.get(3) == 3
+    assert cache.get(4) == 4
+    assert cache.keys() == [4, 3]
+
+
+def test_lru_cache_put_existing_key_updates_and_refreshes():
+    cache = rust_demo.LruCache(2)
+    cache.put(1, 1)
+    cache.put(2, 2)
+    cache.put(1, 10)
+    cache.put(3, 3)  # evicts 2, not 1
+    assert 2 not in cache
+    assert cache.get(1) == 10
+    assert len(cache) == 2
+
+
+def test_lru_cache_rejects_zero_capacity():
+    with pytest.raises(ValueError, match="Capacity must be positive"):
+        rust_demo.LruCache(0)
+
+
+# Trie
+
+
+def test_trie_insert_and_prefix_queries():
+    trie = rust_demo.Trie()
+    for word in ["car", "cart", "care", "cat", "dog"]:
+        assert trie.insert(word)
+    assert not trie.insert("car")
+    assert len(trie) == 5
+    assert trie.words_with_prefix("car") == ["car", "care", "cart"]
+    assert trie.words_with_prefix("ca") == ["car", "care", "cart", "cat"]
+    assert trie.words_with_prefix("x") == []
+    assert trie.starts_with("do")
+    assert not trie.starts_with("z")
+
+
+def test_trie_contains_requires_whole_word():
+    trie = rust_demo.Trie()
+    trie.insert("cart")
+    assert "cart" in trie
+    assert not trie.contains("car")
+    assert trie.starts_with("car")
+
+
+def test_trie_handles_very_long_words():
+    trie = rust_demo.Trie()
+    word = "a" * 200_000
+    trie.insert(word)
+    trie.insert("ab")
+    assert trie.words_with_prefix("") == [word, "ab"]
+    assert trie.words_with_prefix("a" * 1000) == [word]
+
+
+# UnionFind
+
+
+def test_union_find_union_count_connected():
+    uf = rust_demo.UnionFind(5)
+    assert uf.count() == 5
+    assert uf.union(0, 1)
+    assert uf.union(1, 2)
+    assert not uf.union(0, 2)
+    assert uf.count() == 3
+    assert uf.connected(0, 2)
+    assert not uf.connected(0, 3)
+    assert uf.find(2) == uf.find(0)
+
+
+def test_union_find_rejects_out_of_range_index():
+    uf = rust_demo.UnionFind(3)
+    with pytest.raises(IndexError, match="Index 3 out of range for 3 elements"):
+        uf.union(0, 3)
+    with pytest.raises(IndexError):
+        uf.find(10)
+
+
+# MinHeap
+
+
+def test_min_heap_pops_in_ascending_order():
+    heap = rust_demo.MinHeap()
+    for value in [5, 1, 4, 1, 3, -2]:
+        heap.push(value)
+    assert [heap.pop() for _ in range(len(heap))] == [-2, 1, 1, 3, 4, 5]
+    assert heap.pop() is None
+
+
+def test_min_heap_peek_does_not_mutate():
+    heap = rust_demo.MinHeap()
+    heap.push(7)
+    heap.push(3)
+    assert heap.peek() == 3
+    assert heap.peek() == 3
+    assert len(heap) == 2
+
+
+# Stack / Queue
+
+
+def test_stack_is_lifo():
+    stack = rust_demo.Stack()
+    for value in [1, 2, 3]:
+        stack.push(value)
+    assert stack.peek() == 3
+    assert [stack.pop(), stack.pop(), stack.pop()] == [3, 2, 1]
+    assert stack.is_empty()
+    assert stack.pop() is None
+
+
+def test_queue_is_fifo():
+    queue = rust_demo.Queue()
+    for value in [1, 2, 3]:
+        queue.enqueue(value)
+    assert queue.front() == 1
+    assert [queue.dequeue(), queue.dequeue(), queue.dequeue()] == [1, 2, 3]
+    assert queue.is_empty()
+    assert queue.dequeue() is None
+
+
+# FenwickTree
+
+
+def test_fenwick_tree_matches_brute_force_after_updates():
+    values = [3, -1, 4, 1, -5, 9, 2, 6, 5, 3]
+    tree = rust_demo.FenwickTree(values)
+    for index, delta in [(0, 2), (4, 10), (9, -7), (5, 0), (2, -4)]:
+        tree.update(index, delta)
+        values[index] += delta
+        for i in range(len(values)):
+            assert tree.prefix_sum(i) == sum(values[: i + 1])
+    assert tree.range_sum(3, 6) == sum(values[3:7])
+
+
+def test_fenwick_tree_rejects_bad_indices():
+    tree = rust_demo.FenwickTree([1, 2, 3])
+    with pytest.raises(IndexError, match="Index 3 out of range for 3 elements"):
+        tree.update(3, 1)
+    with pytest.raises(IndexError):
+        tree.prefix_sum(5)
+    with pytest.raises(ValueError, match="Invalid range"):
+        tree.range_sum(2, 1)
+
+
+def test_fenwick_tree_sum_overflow_raises():
+    with pytest.raises(OverflowError):
+        rust_demo.FenwickTree([2**62, 2**62])
+    tree = rust_demo.FenwickTree([2**62, 2**62 - 1, 2**62])
+    assert tree.prefix_sum(1) == 2**63 - 1
+    with pytest.raises(OverflowError):
+        tree.prefix_sum(2)
+    with pytest.raises(OverflowError):
+        tree.update(0, 1)
+    assert tree.range_sum(0, 1) == 2**63 - 1
+
+
+# IntervalSet
+
+
+def test_interval_set_overlap_queries():
+    intervals = rust_demo.IntervalSet()
+    intervals.add(1, 3)
+    intervals.add(10, 20)
+    intervals.add(5, 8)
+    assert intervals.overlaps(2, 6)  # overlapping
+    assert intervals.overlaps(3, 4)  # adjacent at 3
+    assert not intervals.overlaps(21, 30)  # disjoint
+    assert not intervals.overlaps(4, 4)
+    assert intervals.query(0, 9) == [(1, 3), (5, 8)]
+    assert intervals.query(8, 10) == [(5, 8), (10, 20)]
+    assert len(intervals) == 3
+
+
+def test_interval_set_rejects_reversed_interval():
+    with pytest.raises(ValueError, match=r"start \(5\) > end \(1\)"):
+        rust_demo.IntervalSet().add(5, 1)
+
+
+# SortedSet
+
+
+def test_sorted_set_from_list_sorts_and_dedups():
+    assert rust_demo.SortedSet.from_list([3, 1, 2, 2]).to_list() == [1, 2, 3]
+
+
+def test_sorted_set_equality_and_hash():
+    a = rust_demo.SortedSet.from_list([1, 2, 3])
+    b = rust_demo.SortedSet()
+    for value in [3, 2, 1]:
+        b.insert(value)
+    c = rust_demo.SortedSet.from_list([1, 2])
+    assert a == b
+    assert hash(a) == hash(b)
+    assert a != c
+    assert len({a, b, c}) == 2
+
+
+def test_sorted_set_clear_then_is_empty():
+    s = rust_demo.SortedSet.from_list([1, 2, 3])
+    assert not s.is_empty()
+    s.clear()
+    assert s.is_empty()
+    assert s.to_list() == []
+
+
+# MovingAverage
+
+
+def test_moving_average_values_keeps_last_window():
+    ma = rust_demo.MovingAverage(2)
+    for value in [1.0, 2.0, 3.0]:
+        ma.add(value)
+    assert ma.values() == [2.0, 3.0]
+    assert ma.average() == 2.5
+
+
+def test_moving_average_median():
+    ma = rust_demo.MovingAverage(4)
+    assert ma.median() == 0.0
+    for value in [4.0, 1.0, 3.0]:
+        ma.add(value)
+    assert ma.median() == 3.0
+    ma.add(2.0)
+    assert ma.median() == 2.5  # window [4, 1, 3, 2]
+
+
+# RollingExtrema
+
+
+def test_rolling_extrema_matches_brute_force():
+    rng = random.Random(42)
+    series = [rng.uniform(-100, 100) for _ in range(500)]
+    for window in (1, 3, 17):
+        extrema = rust_demo.RollingExtrema(window)
+        assert extrema.min() is None and extrema.max() is None
+        for i, value in enumerate(series):
+            extrema.add(value)
+            current = series[max(0, i + 1 - window) : i + 1]
+            assert extrema.min() == min(current)
+            assert extrema.max() == max(current)
@@ -0,0 +1,223 @@
+"""Tests for the text-processing functions."""
+
+import pytest
+import rust_demo
+
+
+# parse_query
+
+
+def test_parse_query_repeated_keys():
+    assert rust_demo.parse_query("a=1&b=2&a=3") == {"a": ["1", "3"], "b": ["2"]}
+
+
+def test_parse_query_percent_encoding():
+    assert rust_demo.parse_query("?name=J%C3%BCrgen&q=a%26b%3Dc") == {
+        "name": ["Jürgen"],
+        "q": ["a&b=c"],
+    }
+
+
+def test_parse_query_plus_is_space():
+    assert rust_demo.parse_query("q=hello+world") == {"q": ["hello world"]}
+
+
+def test_parse_query_bare_key():
+    assert rust_demo.parse_query("flag&x=1") == {"flag": [""], "x": ["1"]}
+
+
+def test_parse_query_malformed_escape_kept_literally():
+    assert rust_demo.parse_query("a=%2&b=%zz") == {"a": ["%2"], "b": ["%zz"]}
+
+
+def test_parse_query_signed_escape_kept_literally():
+    # The sign is not a hex digit, so "%" stays literal and "+" still decodes
+    assert rust_demo.parse_query("a=%+1&b=%-1") == {"a": ["% 1"], "b": ["%-1"]}
+
+
+# html_escape / html_unescape
+
+
+def test_html_escape():
+    assert (
+        rust_demo.html_escape("""<a href="x">Tom & 'Jerry'</a>""")
+        == "&lt;a href=&quot;x&quot;&gt;Tom &amp; &#x27;Jerry&#x27;&lt;/a&gt;"
+    )
+
+
+def test_html_round_trip():
+    samples = ["<b>bold</b>", "a & b", "\"quoted\" 'single'", "plain", "", "ünïcødé <3"]
+    for text in samples:
+        assert rust_demo.html_unescape(rust_demo.html_escape(text)) == text
+
+
+def test_html_unescape_numeric_entities():
+    assert rust_demo.html_unescape("&#169;") == "©"
+    assert rust_demo.html_unescape("&#xA9; &amp; &lt;") == "© & <"
+
+
+# word_wrap
+
+
+def test_word_wrap_width_10():
+    assert (
+        rust_demo.word_wrap("The quick brown fox jumps over the lazy dog", 10)
+        == "The quick\nbrown fox\njumps over\nthe lazy\ndog"
+    )
+
+
+def test_word_wrap_long_word_gets_own_line():
+    wrapped = rust_demo.word_wrap("a supercalifragilistic b", 5)
+    assert wrapped == "a\nsupercalifragilistic\nb"
+
+
+def test_word_wrap_preserves_paragraph_breaks():
+    text = "first paragraph\nwraps here\n\nsecond one"
+    assert rust_demo.word_wrap(text, 20) == "first paragraph\nwraps here\n\nsecond one"
+    assert rust_demo.word_wrap(text, 40) == "first paragraph wraps here\n\nsecond one"
+
+
+# indent / dedent
+
+
+def test_indent_two_lines():
+    assert rust_demo.indent("line one\nline two", "> ") == "> line one\n> line two"
+
+
+def test_indent_skips_blank_lines():
+    assert rust_demo.indent("a\n\nb\n", "    ") == "    a\n\n    b\n"
+
+
+def test_dedent_triple_quoted():
+    text = """
+        def hello():
+            return "hi"
+    """
+    assert rust_demo.dedent(text) == '\ndef hello():\n    return "hi"\n'
+
+
+# pad_left / pad_right / pad_center
+
+
+def test_pad_ab_to_width_5():
+    assert rust_demo.pad_left("ab", 5, "*") == "***ab"
+    assert rust_demo.pad_right("ab", 5, "*") == "ab***"
+    assert rust_demo.pad_center("ab", 5, "*") == "*ab**"
+
+
+def test_pad_already_wide_enough():
+    assert rust_demo.pad_left("abcdef", 3, "*") == "abcdef"
+
+
+def test_pad_counts_graphemes_not_codepoints():
+    e_acute = "e\u0301"  # 'e' + combining acute accent: 2 code points, 1 grapheme
+    flag = "\U0001f1eb\U0001f1f7"  # regional indicators F + R
+    assert rust_demo.pad_left(e_acute, 3, "*") == "**" + e_acute
+    assert rust_demo.pad_right(flag + "x", 4, ".") == flag + "x.."
+    assert rust_demo.pad_center(flag, 4, "-") == "-" + flag + "--"
+
+
+# word_frequencies_sorted
+
+
+def test_word_frequencies_sorted_exact_order():
+    words = ["the", "Cat", "sat", "on", "THE", "mat", "cat"]
+    assert rust_demo.word_frequencies_sorted(words) == [
+        ("cat", 2),
+        ("mat", 1),
+        ("on", 1),
+        ("sat", 1),
+        ("the", 2),
+    ]
+
+
+# group_anagrams / is_anagram
+
+
+def test_group_anagrams_classic_example():
+    words = ["eat", "tea", "tan", "ate", "nat", "bat"]
+    assert rust_demo.group_anagrams(words) == [
+        ["eat", "tea", "ate"],
+        ["tan", "nat"],
+        ["bat"],
+    ]
+
+
+def test_group_anagrams_is_case_insensitive():
+    groups = rust_demo.group_anagrams(["Listen", "silent", "x"])
+    assert groups == [["Listen", "silent"], ["x"]]
+
+
+def test_is_anagram():
+    assert rust_demo.is_anagram("listen", "silent")
+    assert rust_demo.is_anagram("Dormitory", "Dirty Room")
+    assert not rust_demo.is_anagram("Dormitory", "Dirty Room", case_insensitive=False)
+    assert not rust_demo.is_anagram("hello", "world")
+
+
+# can_form_palindrome / palindromic_substrings / longest_palindrome
+
+
+def test_can_form_palindrome():
+    assert rust_demo.can_form_palindrome("Tact Coa")  # "taco cat"
+    assert not rust_demo.can_form_palindrome("hello")
+
+
+def test_palindromic_substrings():
+    assert rust_demo.palindromic_substrings("abba") == ["bb", "abba"]
+    assert rust_demo.palindromic_substrings("aaa") == ["aa", "aaa"]
+    assert rust_demo.palindromic_substrings("abc") == []
+
+
+def test_longest_palindrome():
+    assert rust_demo.longest_palindrome("babad") == "bab"
+    assert rust_demo.longest_palindrome("cbbd") == "bb"
+    assert rust_demo.longest_palindrome("") == ""
+
+
+def test_longest_palindrome_non_ascii():
+    assert rust_demo.longest_palindrome("xyαβγβαq") == "αβγβα"
+    assert rust_demo.longest_palindrome("été") == "été"
+
+
+# tokenize / remove_stopwords / term_frequency
+
+SENTENCE = "Hello, World! Hello again..."
+
+
+@pytest.mark.parametrize(
+    "lowercase, strip_punct, expected",
+    [
+        (False, False, ["Hello,", "World!", "Hello", "again..."]),
+        (True, False, ["hello,", "world!", "hello", "again..."]),
+        (False, True, ["Hello", "World", "Hello", "again"]),
+        (True, True, ["hello", "world", "hello", "again"]),
+    ],
+)
+def test_tokenize_flag_combinations(lowercase, strip_punct, expected):
+    assert rust_demo.tokenize(SENTENCE, lowercase, strip_punct) == expected
+
+
+def test_tokenize_drops_pure_punctuation_tokens():
+    assert rust_demo.tokenize("a -- b", strip_punct=True) == ["a", "b"]
+
+
+def test_count_unique_words_still_case_insensitive():
+    assert rust_demo.count_unique_words("The the THE cat") == 2
+    assert rust_demo.count_unique_words("hello, hello") == 2
+
+
+def test_remove_stopwords_case_insensitive_and_order_preserving():
+    words = ["The", "quick", "fox", "and", "THE", "dog"]
+    assert rust_demo.remove_stopwords(words, ["the", "AND"]) == ["quick", "fox", "dog"]
+    assert rust_demo.remove_stopwords(words, []) == words
+
+
+def test_term_frequency_ratios_sum_to_one():
+    tf = rust_demo.term_frequency(["a", "b", "A", "c"])
+    assert tf == pytest.approx({"a": 0.5, "b": 0.25, "c": 0.25})
+    assert sum(tf.values()) == pytest.approx(1.0)
+
+
+def test_term_frequency_empty_input():
+    assert rust_demo.term_frequency([]) == {}
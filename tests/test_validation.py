@@ -0,0 +1,80 @@
+"""Tests for the validation and networking helpers."""
+
+import pytest
+import rust_demo
+
+
+# luhn_valid / luhn_check_digit
+
+
+def test_luhn_valid():
+    assert rust_demo.luhn_valid("4539 1488 0343 6467")
+    assert rust_demo.luhn_valid("79927398713")
+    assert not rust_demo.luhn_valid("79927398710")
+    assert not rust_demo.luhn_valid("4539-1488-0343-6468")
+    assert not rust_demo.luhn_valid("7992a398713")
+    assert not rust_demo.luhn_valid("0")
+
+
+def test_luhn_check_digit():
+    assert rust_demo.luhn_check_digit("7992739871") == 3
+    number = "4539 1488 0343 646"
+    assert rust_demo.luhn_valid(number + str(rust_demo.luhn_check_digit(number)))
+
+
+def test_luhn_check_digit_rejects_bad_input():
+    with pytest.raises(ValueError, match="Expected digits"):
+        rust_demo.luhn_check_digit("")
+    with pytest.raises(ValueError, match="got '12a'"):
+        rust_demo.luhn_check_digit("12a")
+
+
+# isbn_valid
+
+
+def test_isbn_valid():
+    assert rust_demo.isbn_valid("0-8044-2957-X")
+    assert rust_demo.isbn_valid("080442957x")
+    assert rust_demo.isbn_valid("978-0-306-40615-7")
+    assert rust_demo.isbn_valid("0 306 40615 2")
+
+
+def test_isbn_valid_rejects_bad_checksums_and_formats():
+    assert not rust_demo.isbn_valid("0-8044-2957-1")
+    assert not rust_demo.isbn_valid("978-0-306-40615-8")
+    assert not rust_demo.isbn_valid("X-8044-2957-0")
+    assert not rust_demo.isbn_valid("12345")
+
+
+# ipv4_to_int / int_to_ipv4 / cidr_contains
+
+
+def test_ipv4_round_trip():
+    assert rust_demo.ipv4_to_int("192.168.0.1") == 0xC0A80001
+    assert rust_demo.int_to_ipv4(0xC0A80001) == "192.168.0.1"
+    for addr in ["0.0.0.0", "10.0.0.255", "255.255.255.255"]:
+        assert rust_demo.int_to_ipv4(rust_demo.ipv4_to_int(addr)) == addr
+
+
+@pytest.mark.parametrize("addr", ["256.0.0.1", "1.2.3", "a.b.c.d", ""])
+def test_ipv4_to_int_rejects_invalid_addresses(addr):
+    with pytest.raises(ValueError, match="Invalid IPv4 address"):
+        rust_demo.ipv4_to_int(addr)
+
+
+def test_cidr_contains():
+    assert rust_demo.cidr_contains("192.168.1.0/24", "192.168.1.77")
+    assert not rust_demo.cidr_contains("192.168.1.0/24", "192.168.2.1")
+    assert rust_demo.cidr_contains("10.0.0.0/8", "10.255.255.255")
+    assert rust_demo.cidr_contains("0.0.0.0/0", "8.8.8.8")
+    assert rust_demo.cidr_contains("1.2.3.4/32", "1.2.3.4")
+    assert not rust_demo.cidr_contains("1.2.3.4/32", "1.2.3.5")
+
+
+def test_cidr_contains_rejects_malformed_input():
+    with pytest.raises(ValueError, match="Invalid CIDR range: '192.168.1.0'"):
+        rust_demo.cidr_contains("192.168.1.0", "192.168.1.1")
+    with pytest.raises(ValueError, match="Prefix length must be at most 32, got 33"):
+        rust_demo.cidr_contains("192.168.1.0/33", "192.168.1.1")
+    with pytest.raises(ValueError, match="Invalid IPv4 address: '192.168.1'"):
+        rust_demo.cidr_contains("192.168.1.0/24", "192.168.1")